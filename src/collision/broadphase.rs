@@ -0,0 +1,204 @@
+use ::shape::{Rectangle, Line, Bounded};
+use super::{Collide, RayCast, Sweep};
+use ::{Vector2, Vector2i};
+
+use std::collections::HashMap;
+
+
+/// Sorts objects into a uniform grid of cells by their bounding boxes,
+/// so that only the objects near a region have to be tested for collisions
+pub struct SpatialGrid<T> {
+    // The size of every cell
+    cell_size: Vector2,
+
+    // Handles of the objects whose bounding box touches each cell
+    cells: HashMap<Vector2i, Vec<usize>>,
+
+    // The objects and the bounding boxes they were sorted by, none if removed
+    objects: Vec<Option<(Rectangle, T)>>,
+
+    // Handles of removed objects, reused by the next insertions
+    free: Vec<usize>,
+
+    // The number of objects that are not removed
+    count: usize,
+}
+
+
+impl<T: Bounded> SpatialGrid<T> {
+    /// Create an empty grid
+    ///
+    /// # Arguments
+    ///
+    /// * 'cell_size' - The size of every cell, should be a few times larger than a typical object
+    pub fn new(cell_size: Vector2) -> SpatialGrid<T> {
+        SpatialGrid {
+            cell_size,
+            cells: HashMap::new(),
+            objects: Vec::new(),
+            free: Vec::new(),
+            count: 0,
+        }
+    }
+
+
+    /// Add an object to the grid, returning a handle that identifies it.
+    /// Handles of removed objects are reused.
+    pub fn insert(&mut self, object: T) -> usize {
+        let handle = match self.free.pop() {
+            Some(handle) => handle,
+            None => {
+                self.objects.push(None);
+                self.objects.len() - 1
+            }
+        };
+
+        self.set(handle, object);
+
+        handle
+    }
+
+
+    /// Replace the object with a handle, for example after it has moved
+    pub fn set(&mut self, handle: usize, object: T) {
+        if self.unlink(handle).is_none() {
+            // The handle is no longer free once it holds an object again
+            if let Some(index) = self.free.iter().position(|&free| free == handle) {
+                self.free.swap_remove(index);
+            }
+
+            self.count += 1;
+        }
+
+        let bounds = object.bounding_box();
+        for cell in self.cells_within(&bounds).into_iter() {
+            self.cells.entry(cell).or_insert_with(Vec::new).push(handle);
+        }
+
+        self.objects[handle] = Some((bounds, object));
+    }
+
+
+    /// Remove the object with a handle from the grid, returning it
+    pub fn remove(&mut self, handle: usize) -> Option<T> {
+        match self.unlink(handle) {
+            Some((_, object)) => {
+                self.count -= 1;
+                self.free.push(handle);
+
+                Some(object)
+            }
+
+            None => None
+        }
+    }
+
+
+    /// Take the object with a handle out of its slot and cells, without freeing the handle
+    fn unlink(&mut self, handle: usize) -> Option<(Rectangle, T)> {
+        let (bounds, object) = match self.objects.get_mut(handle) {
+            Some(slot) => match slot.take() {
+                Some(entry) => entry,
+                None => return None
+            },
+
+            None => return None
+        };
+
+        for cell in self.cells_within(&bounds).into_iter() {
+            let is_empty = match self.cells.get_mut(&cell) {
+                Some(handles) => {
+                    handles.retain(|&other| other != handle);
+                    handles.is_empty()
+                }
+
+                None => false
+            };
+
+            if is_empty {
+                self.cells.remove(&cell);
+            }
+        }
+
+        Some((bounds, object))
+    }
+
+
+    /// Return the object with a handle
+    pub fn get(&self, handle: usize) -> Option<&T> {
+        match self.objects.get(handle) {
+            Some(&Some((_, ref object))) => Some(object),
+            _ => None
+        }
+    }
+
+
+    /// Return the number of objects in the grid
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+
+    /// Return the handles of all objects whose bounding box intersects a region
+    pub fn query_region(&self, region: &Rectangle) -> Vec<usize> {
+        self.candidates(region, |bounds| bounds.intersects(region))
+    }
+
+
+    /// Return the handles of all objects whose bounding box is crossed by a line
+    pub fn query_ray(&self, line: &Line) -> Vec<usize> {
+        self.candidates(&line.bounding_box(), |bounds| {
+            bounds.contains(line.start) || bounds.line_intersection(line).is_some()
+        })
+    }
+
+
+    /// Return the handles of all objects whose bounding box is hit by a rectangle moving along a path
+    pub fn query_sweep(&self, rect: &Rectangle, path: Vector2) -> Vec<usize> {
+        self.candidates(&rect.swept_region(path), |bounds| {
+            bounds.intersects(rect) || rect.sweep(path, bounds).is_some()
+        })
+    }
+
+
+    /// Return the handles of the objects in the cells of a region whose bounding box passes a test,
+    /// sorted by handle
+    fn candidates<F>(&self, region: &Rectangle, test: F) -> Vec<usize>
+        where F: Fn(&Rectangle) -> bool
+    {
+        let mut handles = Vec::new();
+
+        for cell in self.cells_within(region).into_iter() {
+            if let Some(cell_handles) = self.cells.get(&cell) {
+                handles.extend(cell_handles.iter().cloned());
+            }
+        }
+
+        // Objects may span multiple cells
+        handles.sort();
+        handles.dedup();
+
+        handles.retain(|&handle| match self.objects[handle] {
+            Some((ref bounds, _)) => test(bounds),
+            None => false
+        });
+
+        handles
+    }
+
+
+    /// Return the cells a region touches
+    fn cells_within(&self, region: &Rectangle) -> Vec<Vector2i> {
+        let min: Vector2i = (region.min / self.cell_size).floor().into();
+        let max: Vector2i = (region.max / self.cell_size).floor().into();
+
+        let mut cells = Vec::new();
+        for x in min.x..max.x + 1 {
+            for y in min.y..max.y + 1 {
+                cells.push(Vector2i::new(x, y));
+            }
+        }
+
+        cells
+    }
+}
@@ -12,7 +12,7 @@ pub trait Collide<C> {
 }
 
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct Overlap {
     /// The depth of the overlap
     pub depth: FloatType,
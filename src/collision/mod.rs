@@ -4,14 +4,20 @@ mod collide;
 pub use self::collide::{Collide, Overlap};
 
 mod ray_cast;
-pub use self::ray_cast::{RayCast, Intersection};
+pub use self::ray_cast::{RayCast, Intersection, RayHit, cast_ray};
+
+mod point_query;
+pub use self::point_query::{PointQuery, Distance};
 
 mod sweep;
-pub use self::sweep::{Sweep, Impact};
+pub use self::sweep::{Sweep, Impact, cast_shape};
 
 mod helpers;
 pub use self::helpers::*;
 
+mod broadphase;
+pub use self::broadphase::SpatialGrid;
+
 
 impl From<Intersection> for Impact {
     fn from(intersection: Intersection) -> Self {
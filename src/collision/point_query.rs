@@ -0,0 +1,29 @@
+use ::{Vector2, FloatType};
+
+
+/// For objects that can be tested against points
+pub trait PointQuery {
+    /// Return true if the object contains a point
+    fn contains_point(&self, point: Vector2) -> bool;
+
+    /// Return the point on the outline of the object that is closest to a point
+    fn closest_point(&self, point: Vector2) -> Vector2;
+
+    /// Return the distance from the outline of the object to a point, negative if the object contains the point
+    fn signed_distance(&self, point: Vector2) -> FloatType {
+        let distance = (point - self.closest_point(point)).length();
+
+        if self.contains_point(point) {
+            -distance
+        } else {
+            distance
+        }
+    }
+}
+
+
+/// For objects that can measure how far apart they are
+pub trait Distance<S> {
+    /// Return the distance between two objects, or the negative depth of their overlap if they intersect
+    fn distance(&self, other: &S) -> FloatType;
+}
@@ -2,11 +2,28 @@
 use ::shape::Line;
 use ::{FloatType, Vector2};
 
+use std::cmp::Ordering;
+
 /// Objects that can be tested for an intersection with a ray
 pub trait RayCast {
     /// Return the first intersection point with the ray
     fn ray_intersection(&self, origin: Vector2, direction: Vector2) -> Option<Intersection>;
 
+    /// Return where the ray enters and exits the object.
+    /// By default the object has no area, the ray leaves where it entered.
+    fn ray_hit(&self, origin: Vector2, direction: Vector2) -> Option<RayHit> {
+        match self.ray_intersection(origin, direction) {
+            Some(entry) => Some(RayHit {
+                entry,
+                exit: Intersection {
+                    normal: -entry.normal,
+                    ..entry
+                },
+            }),
+            None => None
+        }
+    }
+
     /// Return the first intersection point with the line
     fn line_intersection(&self, line: &Line) -> Option<Intersection> {
         // Calculate possible intersections
@@ -35,9 +52,46 @@ pub trait RayCast {
     }
 }
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct Intersection {
     pub time: FloatType,
     pub point: Vector2,
     pub normal: Vector2
 }
+
+
+/// Where a ray passes through an object
+#[derive(Copy, Clone, Debug)]
+pub struct RayHit {
+    /// Where the ray enters the object, the normal faces the ray
+    pub entry: Intersection,
+
+    /// Where the ray exits the object, the normal faces away from the ray
+    pub exit: Intersection,
+}
+
+
+/// Cast a ray through a collection of objects, returning the indices of all objects that were hit
+/// and where the ray passed through them, sorted by the time the ray entered them.
+/// Objects the ray starts in are included, objects behind the ray are not.
+///
+/// # Arguments
+///
+/// * 'objects' - The objects to cast the ray through
+/// * 'origin' - The start of the ray
+/// * 'direction' - The direction of the ray, the length of which is the unit of time of the hits
+pub fn cast_ray<'a, I, T>(objects: I, origin: Vector2, direction: Vector2) -> Vec<(usize, RayHit)>
+    where I: IntoIterator<Item=&'a T>, T: RayCast + ?Sized + 'a
+{
+    let mut hits: Vec<(usize, RayHit)> = objects.into_iter()
+        .enumerate()
+        .filter_map(|(index, object)| match object.ray_hit(origin, direction) {
+            Some(hit) if hit.exit.time >= 0.0 => Some((index, hit)),
+            _ => None
+        })
+        .collect();
+
+    hits.sort_by(|&(_, ref a), &(_, ref b)| a.entry.time.partial_cmp(&b.entry.time).unwrap_or(Ordering::Equal));
+
+    hits
+}
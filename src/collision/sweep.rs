@@ -3,15 +3,57 @@ use ::{FloatType, Vector2};
 
 pub trait Sweep<S> {
     fn sweep(&self, path: Vector2, other: &S) -> Option<Impact>;
+
+    /// Return the first impact of two objects that both move during the same period of time
+    ///
+    /// # Arguments
+    ///
+    /// * 'path' - The movement of this object
+    /// * 'other' - The other object, where it is at the start of the movement
+    /// * 'other_path' - The movement of the other object
+    fn sweep_moving(&self, path: Vector2, other: &S, other_path: Vector2) -> Option<Impact> {
+        // Moving both objects is the same as moving this one relative to the other
+        self.sweep(path - other_path, other)
+    }
 }
 
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct Impact {
     pub time: FloatType,
     pub normal: Vector2
 }
 
+/// Move a shape along a path through a collection of obstacles, returning the index of the first obstacle
+/// it hits and the impact with it
+///
+/// # Arguments
+///
+/// * 'shape' - The shape to move
+/// * 'path' - The movement of the shape
+/// * 'obstacles' - The obstacles the shape could hit
+pub fn cast_shape<'a, S, I, T>(shape: &S, path: Vector2, obstacles: I) -> Option<(usize, Impact)>
+    where S: Sweep<T> + ?Sized, I: IntoIterator<Item=&'a T>, T: 'a
+{
+    let mut first: Option<(usize, Impact)> = None;
+
+    for (index, obstacle) in obstacles.into_iter().enumerate() {
+        if let Some(impact) = shape.sweep(path, obstacle) {
+            let is_first = match first {
+                Some((_, ref first)) => impact.time < first.time,
+                None => true,
+            };
+
+            if is_first {
+                first = Some((index, impact));
+            }
+        }
+    }
+
+    first
+}
+
+
 impl Impact {
     pub fn inverse(self) -> Impact {
         Impact {
@@ -2,10 +2,14 @@
 
 
 use ::{WindowHandle, WindowEventHandler};
+use window::Window;
 use ::Renderer;
 
 use ::FloatType;
 
+use std::collections::VecDeque;
+use std::cell::RefCell;
+
 /// What is a game?
 /// - Contains callback functions for handling rendering and updates to the window
 /// - Handles event callbacks
@@ -20,11 +24,20 @@ pub trait Game: WindowEventHandler {
     fn setup(window: WindowHandle) -> Self;
 
 
-    /// Updates the contents of the game
+    /// Updates the contents of the game.
+    /// Called once every frame, right before the game is rendered.
     fn update(&mut self, info: UpdateInfo);
 
 
-    /// Render the contents of the game to a renderer
+    /// Updates the contents of the game with a constant time step.
+    /// Only called if the game was started with a 'FixedUpdate' in its settings,
+    /// in which case it is called zero or more times every frame, before 'update'.
+    #[allow(unused_variables)]
+    fn fixed_update(&mut self, info: UpdateInfo) {}
+
+
+    /// Render the contents of the game to a renderer.
+    /// The interpolation alpha of the current frame is available through 'WindowHandle::get_alpha'.
     fn render(&self, renderer: &mut Renderer);
 
 
@@ -34,8 +47,200 @@ pub trait Game: WindowEventHandler {
 }
 
 
+#[derive(Copy, Clone, Debug)]
 pub struct UpdateInfo {
     // Change in time, in seconds
-    pub dt: FloatType
+    pub dt: FloatType,
+
+    // How far the game is between the last fixed update and the next, in the range [0, 1).
+    // Used to interpolate between the last two fixed updates when rendering.
+    // Always zero when there are no fixed updates.
+    pub alpha: FloatType,
+
+    // Statistics about the frames so far, including the current one
+    pub stats: FrameStats,
+}
+
+
+/// Statistics about the frames of a game
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// Number of the current frame, starting at zero
+    pub frame: u64,
+
+    /// Time since the game started, in seconds
+    pub time: FloatType,
+
+    /// Average number of frames per second during the last second
+    pub fps: FloatType,
+
+    /// The shortest and longest frame during the last second, in seconds
+    pub min_frame_time: FloatType,
+    pub max_frame_time: FloatType,
+}
+
+
+/// Keeps track of the frames of a game
+pub struct FrameCounter {
+    // Number of frames so far
+    frames: u64,
+
+    // Time since the first frame
+    time: FloatType,
+
+    // The length of the most recent frames
+    recent_frames: VecDeque<FloatType>,
+
+    // The sum of the lengths of the most recent frames
+    recent_time: FloatType,
+}
+
+
+/// The amount of time the averages of the frame statistics span, in seconds
+const AVERAGING_TIME: FloatType = 1.0;
+
+
+/// Settings for calling 'Game::fixed_update' with a constant time step
+#[derive(Copy, Clone, Debug)]
+pub struct FixedUpdate {
+    /// Number of fixed updates per second
+    pub tick_rate: FloatType,
+
+    /// Maximum number of fixed updates in a single frame.
+    /// If the game falls further behind, the remaining time is dropped.
+    pub max_steps: u32,
+}
+
+
+/// Splits the time between frames into fixed updates
+pub struct FixedTimestep {
+    settings: FixedUpdate,
+
+    // Time that hasn't been consumed by a fixed update yet
+    accumulator: FloatType,
+}
+
+
+impl FixedTimestep {
+    pub fn new(settings: FixedUpdate) -> FixedTimestep {
+        FixedTimestep {
+            settings,
+            accumulator: 0.0,
+        }
+    }
+
+
+    /// Return the length of a fixed update, in seconds
+    pub fn get_step(&self) -> FloatType {
+        1.0 / self.settings.tick_rate
+    }
+
+
+    /// Advance time, returning the number of fixed updates to run
+    pub fn advance(&mut self, dt: FloatType) -> u32 {
+        let step = self.get_step();
+        self.accumulator += dt;
+
+        let mut steps = 0;
+        while self.accumulator >= step && steps < self.settings.max_steps {
+            self.accumulator -= step;
+            steps += 1;
+        }
+
+        // Drop the time we can't catch up with
+        if self.accumulator >= step {
+            self.accumulator %= step;
+        }
+
+        steps
+    }
+
+
+    /// Return how far time has advanced towards the next fixed update, in the range [0, 1)
+    pub fn get_alpha(&self) -> FloatType {
+        self.accumulator / self.get_step()
+    }
+}
+
+
+impl FrameCounter {
+    pub fn new() -> FrameCounter {
+        FrameCounter {
+            frames: 0,
+            time: 0.0,
+            recent_frames: VecDeque::new(),
+            recent_time: 0.0,
+        }
+    }
+
+
+    /// Count a new frame, returning the statistics including that frame
+    pub fn tick(&mut self, dt: FloatType) -> FrameStats {
+        self.time += dt;
+        self.recent_frames.push_back(dt);
+        self.recent_time += dt;
+
+        // Only keep the frames within the averaging time, but never less than the current one
+        loop {
+            match self.recent_frames.front() {
+                Some(&oldest) if self.recent_frames.len() > 1 && self.recent_time - oldest >= AVERAGING_TIME => {
+                    self.recent_frames.pop_front();
+                    self.recent_time -= oldest;
+                }
+
+                _ => break
+            }
+        }
+
+        let recent_time = self.recent_time;
+
+        let mut min_frame_time = dt;
+        let mut max_frame_time = dt;
+        for &frame_time in self.recent_frames.iter() {
+            min_frame_time = min!(min_frame_time, frame_time);
+            max_frame_time = max!(max_frame_time, frame_time);
+        }
+
+        let stats = FrameStats {
+            frame: self.frames,
+            time: self.time,
+            fps: if recent_time > 0.0 { self.recent_frames.len() as FloatType / recent_time } else { 0.0 },
+            min_frame_time,
+            max_frame_time,
+        };
+
+        self.frames += 1;
+
+        stats
+    }
+}
+
+
+/// Run the fixed updates and the update of a game for a single frame,
+/// storing the interpolation alpha in the window for rendering
+pub fn update_game<GameType: Game>(game: &mut GameType, window: &RefCell<Window>, dt: FloatType, stats: FrameStats, timestep: Option<&mut FixedTimestep>) {
+    let mut alpha = 0.0;
+
+    if let Some(timestep) = timestep {
+        let steps = timestep.advance(dt);
+
+        for _ in 0..steps {
+            game.fixed_update(UpdateInfo {
+                dt: timestep.get_step(),
+                alpha: 0.0,
+                stats,
+            });
+        }
+
+        alpha = timestep.get_alpha();
+    }
+
+    window.borrow_mut().set_alpha(alpha);
+
+    game.update(UpdateInfo {
+        dt,
+        alpha,
+        stats,
+    });
 }
 
@@ -0,0 +1,166 @@
+
+use ::{Game, App, WindowHandle, Renderer, FixedUpdate};
+use ::FloatType;
+
+use game::{self, FixedTimestep, FrameCounter};
+
+use window::Window;
+use renderer::texture::SoftwareTextureScope;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+
+/// Settings for running a game or an app without a window
+pub struct HeadlessSettings {
+    pub clear_color: [f32; 4],
+
+    /// The time steps to run, one frame is rendered for each step
+    pub time_steps: TimeSteps,
+
+    /// Call 'Game::fixed_update' with a constant time step, none to disable
+    pub fixed_update: Option<FixedUpdate>,
+}
+
+
+/// The time steps used when running without a window
+pub enum TimeSteps {
+    /// A number of frames, each taking the same amount of time, in seconds
+    Fixed { dt: FloatType, frames: u32 },
+
+    /// One frame for each given time step, in seconds
+    Scripted(Vec<FloatType>),
+}
+
+
+/// A game or an app that has been run without a window
+pub struct HeadlessRun<T, R> {
+    /// The game or app, in the state it was left in
+    pub program: T,
+
+    /// The renderer that received all render commands
+    pub renderer: R,
+}
+
+
+impl TimeSteps {
+    /// Return the length of every time step
+    fn to_vec(self) -> Vec<FloatType> {
+        match self {
+            TimeSteps::Fixed { dt, frames } => vec![dt; frames as usize],
+            TimeSteps::Scripted(steps) => steps,
+        }
+    }
+}
+
+
+/// Runs a game without a window
+///
+/// # Description
+///
+/// The game is updated and rendered once for every time step, or until it stops running.
+/// Textures are kept in main memory, so the renderer can't depend on OpenGL.
+///
+/// # Arguments
+///
+/// * 'width' - Width of the stand-in window
+/// * 'height' - Height of the stand-in window
+/// * 'renderer' - Renderer that receives all render commands, for example a 'RecordingRenderer' or a 'Rasterizer'
+pub fn run_game_headless<GameType, R>(width: u32, height: u32, settings: HeadlessSettings, mut renderer: R) -> HeadlessRun<GameType, R>
+    where GameType: Game, R: Renderer
+{
+    // There is no OpenGL context to store textures in
+    let _software_textures = SoftwareTextureScope::new();
+
+    let window = Rc::new(RefCell::new(Window::headless(width, height)));
+
+    // Setup the renderer
+    renderer.set_viewport(0, 0, width, height);
+    renderer.set_clear_color(settings.clear_color);
+
+    // Create game
+    let mut game = GameType::setup(WindowHandle::new(window.clone()));
+
+    // Split time into fixed updates, if enabled
+    let mut timestep = settings.fixed_update.map(FixedTimestep::new);
+
+    // Keep track of frame statistics
+    let mut frame_counter = FrameCounter::new();
+
+    for dt in settings.time_steps.to_vec().into_iter() {
+        if !game.is_running() {
+            break;
+        }
+
+        // Count this frame
+        let stats = frame_counter.tick(dt);
+        window.borrow_mut().set_frame_stats(stats);
+
+        // Update game
+        game::update_game(&mut game, &window, dt, stats, timestep.as_mut());
+
+        // Clear colors
+        renderer.clear();
+
+        // Render game
+        game.render(&mut renderer);
+    }
+
+    HeadlessRun {
+        program: game,
+        renderer,
+    }
+}
+
+
+/// Runs an app without a window
+///
+/// # Description
+///
+/// The app is refreshed once for every time step, or until it stops running.
+/// Textures are kept in main memory, so the renderer can't depend on OpenGL.
+///
+/// # Arguments
+///
+/// * 'width' - Width of the stand-in window
+/// * 'height' - Height of the stand-in window
+/// * 'renderer' - Renderer that receives all render commands, for example a 'RecordingRenderer' or a 'Rasterizer'
+pub fn run_app_headless<AppType, R>(width: u32, height: u32, settings: HeadlessSettings, mut renderer: R) -> HeadlessRun<AppType, R>
+    where AppType: App, R: Renderer
+{
+    // There is no OpenGL context to store textures in
+    let _software_textures = SoftwareTextureScope::new();
+
+    let window = Rc::new(RefCell::new(Window::headless(width, height)));
+
+    // Setup the renderer
+    renderer.set_viewport(0, 0, width, height);
+    renderer.set_clear_color(settings.clear_color);
+
+    // Create an app
+    let app = AppType::setup(WindowHandle::new(window.clone()));
+
+    // Keep track of frame statistics
+    let mut frame_counter = FrameCounter::new();
+
+    for dt in settings.time_steps.to_vec().into_iter() {
+        if !app.is_running() {
+            break;
+        }
+
+        // Count this frame
+        let stats = frame_counter.tick(dt);
+        window.borrow_mut().set_frame_stats(stats);
+
+        // Clear colors
+        renderer.clear();
+
+        // Render app
+        app.render(&mut renderer);
+    }
+
+    HeadlessRun {
+        program: app,
+        renderer,
+    }
+}
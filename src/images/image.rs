@@ -41,6 +41,19 @@ impl Image {
     }
 
 
+    /// Create an image from pixels in RGBA format, row by row starting from the top
+    pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> Image {
+        assert_eq!(pixels.len(), (4 * width * height) as usize);
+
+        Image {
+            format: ImageFormat::RGBA,
+            width,
+            height,
+            buffer: pixels,
+        }
+    }
+
+
     /// Create image from a dynamic image
     fn from_dynamic(image: image::DynamicImage) -> Image {
         let rgba = image.to_rgba();
@@ -125,6 +138,19 @@ impl Image {
         self.height
     }
 
+    /// Get the color of a pixel, counting from the top left corner
+    pub fn get_pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let bytes_per_pixel = match self.format {
+            ImageFormat::RGBA => 4
+        };
+
+        let index = (bytes_per_pixel * (x + y * self.width)) as usize;
+
+        let mut pixel = [0; 4];
+        pixel.copy_from_slice(&self.buffer[index..index + 4]);
+        pixel
+    }
+
     /// Get the bytes in the buffer
     pub fn as_bytes(&self) -> &[u8] {
         &self.buffer
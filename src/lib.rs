@@ -30,7 +30,8 @@ mod gl;
 /// Things related to a Game
 mod game;
 
-pub use game::{Game, UpdateInfo};
+pub use game::{Game, UpdateInfo, FixedUpdate, FrameStats};
+use game::{FixedTimestep, FrameCounter};
 
 /// Things related to an App
 mod app;
@@ -40,10 +41,12 @@ pub use app::App;
 /// Things related to Rendering
 mod renderer;
 
-pub use renderer::{Renderer, RenderBatch};
+pub use renderer::{Renderer, RenderBatch, Camera};
+pub use renderer::{RecordingRenderer, RenderCommand, RecordedBatch, RecordedMesh, Rasterizer};
+pub use renderer::OpenGLRenderer;
 pub use renderer::view::*;
 pub use renderer::vertex::Vertex;
-pub use renderer::texture::{Texture, TextureData, TextureFilter};
+pub use renderer::texture::{Texture, TextureData, TextureFilter, SoftwareTexture, SoftwareTextureScope, software_textures_enabled};
 
 
 /// Images
@@ -52,6 +55,12 @@ mod images;
 pub use images::{Image, ImageFormat};
 
 
+/// Running without a window
+mod headless;
+
+pub use headless::{run_game_headless, run_app_headless, HeadlessSettings, HeadlessRun, TimeSteps};
+
+
 /// Things related to a window
 mod window;
 
@@ -86,7 +95,6 @@ use std::time::Instant;
 /// Various std
 use std::rc::Rc;
 use std::cell::RefCell;
-use std::ops::Deref;
 
 
 /// Starts a new game in a window
@@ -117,7 +125,7 @@ pub fn run_game<GameType: Game>(width: u32, height: u32, title: &str, settings:
     };
 
     // Create a renderer
-    let mut renderer = Renderer::new(window.borrow().deref());
+    let mut renderer = OpenGLRenderer::new(&WindowHandle::new(window.clone()));
     renderer.set_clear_color(settings.clear_color);
 
     // Create game
@@ -128,6 +136,11 @@ pub fn run_game<GameType: Game>(width: u32, height: u32, title: &str, settings:
     // Run the game //
     //////////////////
 
+    // Split time into fixed updates, if enabled
+    let mut timestep = settings.fixed_update.map(FixedTimestep::new);
+
+    // Keep track of frame statistics
+    let mut frame_counter = FrameCounter::new();
 
     // Measure the time each iteration of the game loop takes to complete
     let mut last_iteration_time = Instant::now();
@@ -137,7 +150,7 @@ pub fn run_game<GameType: Game>(width: u32, height: u32, title: &str, settings:
         // Setup OpenGL viewport
         let window_size = window.borrow().get_size(); // Rc::get_mut(&mut window).unwrap().get_size();
         if let Some((w, h)) = window_size {
-            unsafe { gl::Viewport(0, 0, w as i32, h as i32) };
+            renderer.set_viewport(0, 0, w, h);
         }
 
         // Measure the time the last iteration took
@@ -152,10 +165,12 @@ pub fn run_game<GameType: Game>(width: u32, height: u32, title: &str, settings:
             window::handle_event(&window, event.clone(), &mut game);
         }
 
+        // Count this frame
+        let stats = frame_counter.tick(elapsed_time_secs);
+        window.borrow_mut().set_frame_stats(stats);
+
         // Update game
-        game.update(UpdateInfo {
-            dt: elapsed_time_secs
-        });
+        game::update_game(&mut game, &window, elapsed_time_secs, stats, timestep.as_mut());
 
         // Clear colors
         renderer.clear();
@@ -176,7 +191,10 @@ pub fn run_game<GameType: Game>(width: u32, height: u32, title: &str, settings:
 /// Settings for a game
 pub struct GameSettings {
     pub vertical_sync: bool,
-    pub clear_color: [f32; 4]
+    pub clear_color: [f32; 4],
+
+    /// Call 'Game::fixed_update' with a constant time step, none to disable
+    pub fixed_update: Option<FixedUpdate>,
 }
 
 
@@ -217,9 +235,15 @@ pub fn run_app<AppType: App>(width: u32, height: u32, title: &str, settings: App
     /////////////////
 
     // Create a renderer
-    let mut renderer = Renderer::new(window.borrow().deref());
+    let mut renderer = OpenGLRenderer::new(&WindowHandle::new(window.clone()));
     renderer.set_clear_color(settings.clear_color);
 
+    // Keep track of frame statistics
+    let mut frame_counter = FrameCounter::new();
+
+    // Measure the time between each rendered frame
+    let mut last_render_time = Instant::now();
+
 
     // Run the game loop for as long as the window and the game is open
     while app.is_running() && window.borrow().is_open() {
@@ -228,7 +252,7 @@ pub fn run_app<AppType: App>(width: u32, height: u32, title: &str, settings: App
         for event in window_events.iter() {
             // Setup OpenGL viewport
             if let &WindowEvent::Resized(w, h) = event {
-                unsafe { gl::Viewport(0, 0, w as i32, h as i32) };
+                renderer.set_viewport(0, 0, w, h);
             }
 
             let event = glutin::Event::WindowEvent {
@@ -245,6 +269,16 @@ pub fn run_app<AppType: App>(width: u32, height: u32, title: &str, settings: App
         //////////////////////////////////////////
 
         if window_events.len() > 0 {
+            // Measure the time since the last frame
+            let current_render_time = Instant::now();
+            let elapsed_time = current_render_time - last_render_time;
+            let elapsed_time_secs: FloatType = elapsed_time.as_secs() as FloatType + elapsed_time.subsec_nanos() as FloatType / 1e9;
+            last_render_time = current_render_time;
+
+            // Count this frame
+            let stats = frame_counter.tick(elapsed_time_secs);
+            window.borrow_mut().set_frame_stats(stats);
+
             // Clear colors
             renderer.clear();
 
@@ -5,6 +5,9 @@ pub use self::vector2::*;
 pub mod vector2i;
 pub use self::vector2i::*;
 
+pub mod transform;
+pub use self::transform::Transform;
+
 
 pub type IntType = i64;
 pub type FloatType = f64;
@@ -0,0 +1,118 @@
+use super::{Vector2, FloatType};
+use std::ops::Mul;
+
+/// An affine transformation of 2D space: a linear transformation followed by a translation
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Transform {
+    /// Where the x and y axes end up after the linear transformation
+    pub x_axis: Vector2,
+    pub y_axis: Vector2,
+
+    /// How far points are moved after the linear transformation
+    pub translation: Vector2,
+}
+
+
+impl Transform {
+    /// Create a transform that leaves all points where they are
+    pub fn identity() -> Transform {
+        Transform {
+            x_axis: Vector2::new(1.0, 0.0),
+            y_axis: Vector2::new(0.0, 1.0),
+            translation: Vector2::zero(),
+        }
+    }
+
+    /// Create a transform that moves all points by the same amount
+    pub fn translate(delta: Vector2) -> Transform {
+        Transform {
+            translation: delta,
+            ..Transform::identity()
+        }
+    }
+
+    /// Create a transform that rotates counter-clockwise around the origin by an angle in radians
+    pub fn rotate(angle: FloatType) -> Transform {
+        Transform {
+            x_axis: Vector2::new(1.0, 0.0).rotate(angle),
+            y_axis: Vector2::new(0.0, 1.0).rotate(angle),
+            translation: Vector2::zero(),
+        }
+    }
+
+    /// Create a transform that scales each axis away from the origin
+    pub fn scale(scale: Vector2) -> Transform {
+        Transform {
+            x_axis: Vector2::new(scale.x, 0.0),
+            y_axis: Vector2::new(0.0, scale.y),
+            translation: Vector2::zero(),
+        }
+    }
+
+
+    /// Return a transform that applies this transform first and another one afterwards
+    pub fn then(self, other: Transform) -> Transform {
+        other * self
+    }
+
+    /// Return the transform that undoes this transform, none if it collapses space onto a line or point
+    pub fn inverse(&self) -> Option<Transform> {
+        let determinant = self.x_axis.cross(self.y_axis);
+        if determinant == 0.0 {
+            return None;
+        }
+
+        let linear = Transform {
+            x_axis: Vector2::new(self.y_axis.y, -self.x_axis.y) / determinant,
+            y_axis: Vector2::new(-self.y_axis.x, self.x_axis.x) / determinant,
+            translation: Vector2::zero(),
+        };
+
+        Some(Transform {
+            translation: -linear.transform_vector(self.translation),
+            ..linear
+        })
+    }
+
+
+    /// Transform a point, which is affected by translation
+    pub fn transform_point(&self, point: Vector2) -> Vector2 {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Transform a direction or offset, which is not affected by translation
+    pub fn transform_vector(&self, vector: Vector2) -> Vector2 {
+        vector.x * self.x_axis + vector.y * self.y_axis
+    }
+
+
+    /// Return the transform as a 3x3 matrix in column-major order
+    pub fn to_matrix(&self) -> [[f32; 3]; 3] {
+        [
+            [self.x_axis.x as f32, self.x_axis.y as f32, 0.0],
+            [self.y_axis.x as f32, self.y_axis.y as f32, 0.0],
+            [self.translation.x as f32, self.translation.y as f32, 1.0],
+        ]
+    }
+}
+
+
+impl Default for Transform {
+    fn default() -> Self {
+        Transform::identity()
+    }
+}
+
+
+/// Combine two transforms, the right one is applied first
+impl Mul<Transform> for Transform {
+    type Output = Transform;
+
+    fn mul(self, other: Transform) -> Transform {
+        Transform {
+            x_axis: self.transform_vector(other.x_axis),
+            y_axis: self.transform_vector(other.y_axis),
+            translation: self.transform_point(other.translation),
+        }
+    }
+}
@@ -26,6 +26,11 @@ impl Vector2 {
         (self.x * other.x + self.y * other.y)
     }
 
+    /// Compute the z component of the cross product, as if both vectors were 3D with z = 0
+    pub fn cross(self, other: Vector2) -> FloatType {
+        self.x * other.y - self.y * other.x
+    }
+
     /// Return the length/magnitude of the vector
     pub fn length(self) -> FloatType {
         (self.x * self.x + self.y * self.y).sqrt()
@@ -63,6 +68,16 @@ impl Vector2 {
         }
     }
 
+    /// Return the vector rotated counter-clockwise by an angle in radians
+    pub fn rotate(self, angle: FloatType) -> Vector2 {
+        let (sin, cos) = angle.sin_cos();
+
+        Vector2 {
+            x: cos * self.x - sin * self.y,
+            y: sin * self.x + cos * self.y
+        }
+    }
+
 
     pub fn round(&self) -> Vector2 {
         Vector2 {
@@ -0,0 +1,153 @@
+use ::collision::Impact;
+use ::{FloatType, Vector2};
+
+use std::slice;
+use std::vec;
+
+
+/// The smallest upwards component of a normal for a surface to count as ground or ceiling.
+/// Surfaces steeper than about 45 degrees count as walls.
+const FLAT_NORMAL_Y: FloatType = 0.7;
+
+
+/// A collision between a physics object and an obstacle or another object
+#[derive(Copy, Clone, Debug)]
+pub struct Contact {
+    /// What was hit
+    pub target: ContactTarget,
+
+    /// When it was hit, and the normal pointing away from it
+    pub impact: Impact,
+}
+
+
+/// What a physics object came into contact with
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ContactTarget {
+    /// A static or kinematic obstacle.
+    /// Identified by its handle in a physics world, or by its index in the obstacles passed to 'PhysicsObject::tick'.
+    Static(usize),
+
+    /// Another body in a physics world, identified by its handle
+    Body(usize),
+}
+
+
+/// All contacts of a physics object during a single step, in the order they happened
+#[derive(Clone, Debug, Default)]
+pub struct Contacts {
+    contacts: Vec<Contact>,
+}
+
+
+impl Contact {
+    /// Return true if the obstacle is below the object, flat enough to stand on
+    pub fn is_ground(&self) -> bool {
+        self.impact.normal.y >= FLAT_NORMAL_Y
+    }
+
+    /// Return true if the obstacle is above the object
+    pub fn is_ceiling(&self) -> bool {
+        self.impact.normal.y <= -FLAT_NORMAL_Y
+    }
+
+    /// Return true if the obstacle is to the side of the object
+    pub fn is_wall(&self) -> bool {
+        !self.is_ground() && !self.is_ceiling()
+    }
+}
+
+
+impl Contacts {
+    /// Create an empty list of contacts
+    pub fn new() -> Contacts {
+        Contacts {
+            contacts: Vec::new()
+        }
+    }
+
+
+    /// Add a contact to the end of the list
+    pub fn push(&mut self, contact: Contact) {
+        self.contacts.push(contact);
+    }
+
+
+    /// Iterate over all contacts, in the order they happened
+    pub fn iter<'a>(&'a self) -> slice::Iter<'a, Contact> {
+        self.contacts.iter()
+    }
+
+
+    /// Return the number of contacts
+    pub fn len(&self) -> usize {
+        self.contacts.len()
+    }
+
+
+    /// Return true if there were no contacts
+    pub fn is_empty(&self) -> bool {
+        self.contacts.is_empty()
+    }
+
+
+    /// Return the first contact with the ground, if any
+    pub fn get_ground(&self) -> Option<&Contact> {
+        self.contacts.iter().find(|contact| contact.is_ground())
+    }
+
+    /// Return the first contact with a wall, if any
+    pub fn get_wall(&self) -> Option<&Contact> {
+        self.contacts.iter().find(|contact| contact.is_wall())
+    }
+
+    /// Return the first contact with a ceiling, if any
+    pub fn get_ceiling(&self) -> Option<&Contact> {
+        self.contacts.iter().find(|contact| contact.is_ceiling())
+    }
+
+
+    /// Return true if the object is standing on the ground
+    pub fn on_ground(&self) -> bool {
+        self.get_ground().is_some()
+    }
+
+    /// Return true if the object is touching a wall
+    pub fn on_wall(&self) -> bool {
+        self.get_wall().is_some()
+    }
+
+    /// Return true if the object hit a ceiling
+    pub fn on_ceiling(&self) -> bool {
+        self.get_ceiling().is_some()
+    }
+
+
+    /// Return the normal of the wall the object is touching, pointing away from the wall
+    pub fn get_wall_normal(&self) -> Option<Vector2> {
+        match self.get_wall() {
+            Some(contact) => Some(contact.impact.normal),
+            None => None
+        }
+    }
+}
+
+
+impl IntoIterator for Contacts {
+    type Item = Contact;
+    type IntoIter = vec::IntoIter<Contact>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contacts.into_iter()
+    }
+}
+
+
+impl<'a> IntoIterator for &'a Contacts {
+    type Item = &'a Contact;
+    type IntoIter = slice::Iter<'a, Contact>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.contacts.iter()
+    }
+}
@@ -1,23 +1,42 @@
 use ::collision::{Collide, Impact, Sweep};
+use ::shape::Translate;
 use ::{FloatType, Vector2};
 
+mod contacts;
+pub use self::contacts::{Contact, ContactTarget, Contacts};
+
+mod obstacle;
+pub use self::obstacle::{Obstacle, CollisionFilter};
+
+mod world;
+pub use self::world::{PhysicsWorld, RigidBody, ContactEvent, TriggerEvent, TriggerPhase};
+
 pub trait Body<T>: Collide<T> + Sweep<T> {}
 
-impl<T> Body<T> for T where T: Collide<T> + Sweep<T> {}
+impl<T, S> Body<S> for T where T: Collide<S> + Sweep<S> {}
 
 pub trait PhysicsObject {
     type CollisionBody: Clone;
 
-    fn tick(&mut self, dt: FloatType, obstacles: &[Box<&Body<Self::CollisionBody>>]) {
+    /// Move the object, returning all contacts with the obstacles
+    fn tick(&mut self, dt: FloatType, obstacles: &[Obstacle<Self::CollisionBody>]) -> Contacts
+        where Self::CollisionBody: Translate
+    {
         self.update_velocity(dt);
-        self.update_position(dt, obstacles);
+        self.update_position(dt, obstacles)
     }
 
+    /// Accelerate the object by gravity and slow it down by drag
     fn update_velocity(&mut self, dt: FloatType) {
+        // Gravity accelerates all objects equally, regardless of their mass
+        let velocity = self.get_velocity();
+        self.set_velocity(velocity + self.get_gravity() * dt);
+
+        // Drag slows all objects down equally, regardless of their mass
         let velocity = self.get_velocity();
         let drag = self.get_drag();
 
-        self.apply_force(drag * velocity * -dt);
+        self.set_velocity(velocity - drag * velocity * dt);
     }
 
     fn get_position(&self) -> Vector2;
@@ -29,45 +48,132 @@ pub trait PhysicsObject {
     fn get_drag(&self) -> Vector2;
     fn set_drag(&mut self, drag: Vector2);
 
-    fn apply_force(&mut self, force: Vector2) {
+    /// Return the mass of the object, infinite for objects that can't be pushed
+    fn get_mass(&self) -> FloatType { 1.0 }
+
+    /// Return one over the mass of the object, zero if the mass is infinite
+    fn get_inverse_mass(&self) -> FloatType {
+        let mass = self.get_mass();
+
+        if mass > 0.0 && mass.is_finite() {
+            1.0 / mass
+        } else {
+            0.0
+        }
+    }
+
+    /// Return how much of its speed the object keeps when bouncing off an obstacle, in the range [0, 1]
+    fn get_restitution(&self) -> FloatType { 0.0 }
+
+    /// Return how strongly the object sticks to a surface it rests on
+    fn get_static_friction(&self) -> FloatType { 0.0 }
+
+    /// Return how strongly a surface slows down the object while it slides along it
+    fn get_dynamic_friction(&self) -> FloatType { 0.0 }
+
+    /// Return the acceleration caused by gravity
+    fn get_gravity(&self) -> Vector2 { Vector2::zero() }
+
+    /// Push the object with a force that acts continuously over a period of time
+    ///
+    /// # Arguments
+    ///
+    /// * 'force' - The force, which is divided by the mass of the object
+    /// * 'dt' - How long the force acts on the object, in seconds
+    fn apply_force(&mut self, force: Vector2, dt: FloatType) {
         let velocity = self.get_velocity();
+        let inverse_mass = self.get_inverse_mass();
 
-        self.set_velocity(velocity + force);
+        self.set_velocity(velocity + inverse_mass * dt * force);
+    }
+
+    /// Push the object with an instantaneous impulse, which is divided by the mass of the object
+    fn apply_impulse(&mut self, impulse: Vector2) {
+        let velocity = self.get_velocity();
+        let inverse_mass = self.get_inverse_mass();
+
+        self.set_velocity(velocity + inverse_mass * impulse);
     }
 
     fn get_collider<'a>(&'a self) -> &'a Self::CollisionBody;
 
+    /// Return which obstacles the object collides with
+    fn get_collision_filter(&self) -> CollisionFilter { CollisionFilter::default() }
 
-    fn update_position(&mut self, dt: FloatType, obstacles: &[Box<&Body<Self::CollisionBody>>]) {
+
+    fn update_position(&mut self, dt: FloatType, obstacles: &[Obstacle<Self::CollisionBody>]) -> Contacts
+        where Self::CollisionBody: Translate
+    {
+        self.update_position_with_offset(dt, Vector2::zero(), obstacles)
+    }
+
+    /// Move the object by its velocity and an offset that is not part of its velocity,
+    /// for example the movement of a platform it stands on, returning all contacts with the obstacles
+    ///
+    /// # Arguments
+    ///
+    /// * 'dt' - The length of the step, in seconds
+    /// * 'offset' - How far the object is moved during the step, in addition to its velocity
+    /// * 'obstacles' - What the object collides with
+    fn update_position_with_offset(&mut self, dt: FloatType, mut offset: Vector2, obstacles: &[Obstacle<Self::CollisionBody>]) -> Contacts
+        where Self::CollisionBody: Translate
+    {
+        let mut contacts = Contacts::new();
+        let filter = self.get_collision_filter();
 
         // self.set_position(position + delta);
         let mut remaining_time = 1.0;
 
         while remaining_time > 0.0 {
-            let mut delta = self.get_velocity() * dt * remaining_time;
+            let mut delta = (self.get_velocity() * dt + offset) * remaining_time;
 
             let this_collider = self.get_collider().clone();
 
-            let mut first: Option<Impact> = None;
+            // The index of the obstacle that is hit first, and the impact with it
+            let mut first: Option<(usize, Impact)> = None;
+
+            // Moving obstacles have already moved part of the way
+            let elapsed = 1.0 - remaining_time;
+
+            for (index, obstacle) in obstacles.iter().enumerate() {
+                if !obstacle.filter.collides_with(&filter) {
+                    continue;
+                }
+
+                // Sweep relative to the obstacle, as if it stayed where it started
+                let obstacle_offset = obstacle.velocity * dt;
+                let path = delta - remaining_time * obstacle_offset;
+                let collider = if elapsed == 0.0 || obstacle_offset == Vector2::zero() {
+                    this_collider.clone()
+                } else {
+                    self.get_moved_collider(-elapsed * obstacle_offset)
+                };
+
+                if let Some(impact) = obstacle.body.sweep(-path, &collider) {
+                    let impact = impact.inverse();
+
+                    if !obstacle.blocks(path, impact.normal) {
+                        continue;
+                    }
 
-            for obstacle in obstacles {
-                if let Some(impact) = obstacle.sweep(-delta, &this_collider) {
                     if let Some(ref mut first) = first {
-                        if impact.time < first.time {
-                            *first = impact.inverse();
+                        if impact.time < first.1.time {
+                            *first = (index, impact);
                         }
                     } else {
-                        first = Some(impact.inverse());
+                        first = Some((index, impact));
                     }
                 }
             }
 
 
-            if let Some(impact) = first {
+            if let Some((index, impact)) = first {
                 delta *= impact.time;
                 remaining_time *= 1.0 - impact.time;
 
-                self.handle_impact(impact);
+                self.handle_relative_impact(impact, obstacles[index].velocity);
+                offset = slide_offset(offset, impact);
+                contacts.push(Contact { target: ContactTarget::Static(index), impact });
             } else {
                 remaining_time = 0.0;
             }
@@ -75,9 +181,31 @@ pub trait PhysicsObject {
             let position = self.get_position();
             self.set_position(position + delta);
 
-            // Avoid overlaps
-            for obstacle in obstacles {
-                if let Some(overlap) = obstacle.overlap(&this_collider) {
+            // Moving obstacles have moved further by now
+            let moved_elapsed = 1.0 - remaining_time;
+
+            // Avoid overlaps, objects are allowed to move through one way obstacles
+            for (index, obstacle) in obstacles.iter().enumerate() {
+                if !obstacle.filter.collides_with(&filter) || obstacle.one_way.is_some() {
+                    continue;
+                }
+
+                let (collider, moved_collider) = if obstacle.velocity == Vector2::zero() {
+                    (this_collider.clone(), self.get_collider().clone())
+                } else {
+                    // The object has already moved by 'delta' during this iteration
+                    (
+                        self.get_moved_collider(-delta - elapsed * obstacle.velocity * dt),
+                        self.get_moved_collider(-moved_elapsed * obstacle.velocity * dt)
+                    )
+                };
+
+                // Objects that moved away from an obstacle they touched, for example by bouncing off it, no longer overlap it
+                if !obstacle.body.intersects(&collider) {
+                    continue;
+                }
+
+                if let Some(overlap) = obstacle.body.overlap(&moved_collider) {
                     let position = self.get_position();
                     let normal = overlap.resolve.normal();
 
@@ -89,19 +217,103 @@ pub trait PhysicsObject {
                         normal: -normal
                     };
 
-                    self.handle_impact(impact);
+                    self.handle_relative_impact(impact, obstacle.velocity);
+                    offset = slide_offset(offset, impact);
+                    contacts.push(Contact { target: ContactTarget::Static(index), impact });
                     break;
                 }
             }
         }
+
+        contacts
+    }
+
+    /// Return a copy of the collider as if the object was moved by an offset
+    fn get_moved_collider(&self, offset: Vector2) -> Self::CollisionBody
+        where Self::CollisionBody: Translate
+    {
+        let mut collider = self.get_collider().clone();
+        collider.translate(offset);
+        collider
     }
 
+    /// Handle an impact with an obstacle that moves with a velocity
+    fn handle_relative_impact(&mut self, impact: Impact, obstacle_velocity: Vector2) {
+        let velocity = self.get_velocity();
+        self.set_velocity(velocity - obstacle_velocity);
+
+        self.handle_impact(impact);
+
+        let velocity = self.get_velocity();
+        self.set_velocity(velocity + obstacle_velocity);
+    }
+
+    /// Bounce off and slide along the surface that was hit
     fn handle_impact(&mut self, impact: Impact) {
         let velocity = self.get_velocity();
-        let rotated_normal: Vector2 = [impact.normal.y, impact.normal.x].into();
 
-        let dot = velocity.dot(rotated_normal);
+        // Only resolve impacts the object is moving into
+        let normal_speed = velocity.dot(impact.normal);
+        if normal_speed >= 0.0 {
+            return;
+        }
 
-        self.set_velocity(dot * rotated_normal);
+        let normal_velocity = normal_speed * impact.normal;
+        let tangent_velocity = velocity - normal_velocity;
+
+        // How much the speed along the normal changes when bouncing
+        let restitution = self.get_restitution();
+        let normal_impulse = -(1.0 + restitution) * normal_speed;
+
+        let friction = friction(
+            tangent_velocity,
+            normal_impulse,
+            self.get_static_friction(),
+            self.get_dynamic_friction()
+        );
+
+        self.set_velocity(tangent_velocity + friction - restitution * normal_velocity);
+    }
+}
+
+
+/// Return the part of an offset that doesn't move into a surface that was hit
+///
+/// # Arguments
+///
+/// * 'offset' - How far the object is moved in addition to its velocity
+/// * 'impact' - The impact with the surface
+fn slide_offset(offset: Vector2, impact: Impact) -> Vector2 {
+    let approach = offset.dot(impact.normal);
+
+    if approach < 0.0 {
+        offset - approach * impact.normal
+    } else {
+        offset
     }
 }
+
+
+/// Return the change in velocity along a surface caused by friction
+///
+/// # Arguments
+///
+/// * 'tangent_velocity' - The velocity along the surface
+/// * 'normal_impulse' - The change in speed along the surface's normal, caused by the impact
+/// * 'static_friction' - How strongly an object sticks to the surface
+/// * 'dynamic_friction' - How strongly the surface slows down a sliding object
+fn friction(tangent_velocity: Vector2, normal_impulse: FloatType, static_friction: FloatType, dynamic_friction: FloatType) -> Vector2 {
+    let tangent_speed = tangent_velocity.length();
+    if tangent_speed == 0.0 {
+        return Vector2::zero();
+    }
+
+    // Stick to the surface if the impact was strong enough, otherwise slide and slow down
+    let reduction = if tangent_speed <= static_friction * normal_impulse {
+        tangent_speed
+    } else {
+        min!(dynamic_friction * normal_impulse, tangent_speed)
+    };
+
+    -reduction / tangent_speed * tangent_velocity
+}
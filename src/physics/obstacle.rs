@@ -0,0 +1,111 @@
+use super::Body;
+use ::Vector2;
+
+
+/// Decides which objects collide with each other.
+///
+/// Two objects collide if each of them is on a layer the other's mask contains.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CollisionFilter {
+    /// The layers the object is on, one bit per layer
+    pub layers: u32,
+
+    /// The layers the object collides with, one bit per layer
+    pub mask: u32,
+}
+
+
+/// Something a physics object can collide with while it moves
+pub struct Obstacle<'a, C: 'a> {
+    /// The shape of the obstacle
+    pub body: &'a Body<C>,
+
+    /// Which objects the obstacle blocks
+    pub filter: CollisionFilter,
+
+    /// If set, the obstacle only blocks objects moving against this direction.
+    /// For example, a platform that can be jumped through from below has the direction [0, 1].
+    pub one_way: Option<Vector2>,
+
+    /// How fast the obstacle moves during the step, zero for static obstacles
+    pub velocity: Vector2,
+}
+
+
+impl CollisionFilter {
+    /// Create a new filter
+    ///
+    /// # Arguments
+    ///
+    /// * 'layers' - The layers the object is on, one bit per layer
+    /// * 'mask' - The layers the object collides with, one bit per layer
+    pub fn new(layers: u32, mask: u32) -> CollisionFilter {
+        CollisionFilter { layers, mask }
+    }
+
+
+    /// Return true if objects with these filters collide with each other
+    pub fn collides_with(&self, other: &CollisionFilter) -> bool {
+        self.layers & other.mask != 0 && other.layers & self.mask != 0
+    }
+}
+
+
+impl Default for CollisionFilter {
+    /// On the first layer, colliding with all layers
+    fn default() -> Self {
+        CollisionFilter {
+            layers: 1,
+            mask: !0,
+        }
+    }
+}
+
+
+impl<'a, C> Obstacle<'a, C> {
+    /// Create an obstacle that blocks all objects from all directions
+    pub fn new(body: &'a Body<C>) -> Obstacle<'a, C> {
+        Obstacle {
+            body,
+            filter: CollisionFilter::default(),
+            one_way: None,
+            velocity: Vector2::zero(),
+        }
+    }
+
+
+    /// Set which objects the obstacle blocks
+    pub fn with_filter(mut self, filter: CollisionFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+
+    /// Only block objects moving against a direction
+    pub fn with_one_way(mut self, direction: Vector2) -> Self {
+        self.one_way = Some(direction);
+        self
+    }
+
+
+    /// Set how fast the obstacle moves during the step
+    pub fn with_velocity(mut self, velocity: Vector2) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+
+    /// Return true if the obstacle stops an object that hits it while moving along a path
+    ///
+    /// # Arguments
+    ///
+    /// * 'path' - The movement of the object
+    /// * 'normal' - The normal of the impact, pointing towards the object
+    pub fn blocks(&self, path: Vector2, normal: Vector2) -> bool {
+        match self.one_way {
+            // Only the side facing the direction blocks
+            Some(direction) => normal.dot(direction) > 0.0 && path.dot(direction) < 0.0,
+            None => true
+        }
+    }
+}
@@ -0,0 +1,978 @@
+use super::{PhysicsObject, Body, Contact, ContactTarget, Contacts, Obstacle, CollisionFilter, friction};
+use ::collision::{SpatialGrid, Overlap, Impact};
+use ::shape::{Rectangle, Bounded, Translate};
+use ::{FloatType, Vector2};
+
+use std::cmp::Ordering;
+
+
+/// A body that is moved by a physics world
+#[derive(Clone, Debug)]
+pub struct RigidBody<C> {
+    pub collider: C,
+    pub velocity: Vector2,
+    pub drag: Vector2,
+
+    /// Infinite for bodies that can't be pushed
+    pub mass: FloatType,
+
+    /// How much speed is kept when bouncing, in the range [0, 1]
+    pub restitution: FloatType,
+
+    /// How strongly the body sticks to and slides along surfaces
+    pub static_friction: FloatType,
+    pub dynamic_friction: FloatType,
+
+    /// How strongly the world's gravity pulls on the body
+    pub gravity_scale: FloatType,
+
+    /// Which obstacles and other bodies the body collides with
+    pub filter: CollisionFilter,
+
+    /// Contacts with obstacles and other bodies during the last step, identified by their handles
+    pub contacts: Contacts,
+
+    // The gravity of the world the body is stepped in, before scaling
+    world_gravity: Vector2,
+}
+
+
+/// A contact that happened while stepping a physics world
+#[derive(Copy, Clone, Debug)]
+pub struct ContactEvent {
+    /// Handle of the body that made contact
+    pub body: usize,
+
+    /// What the body came into contact with
+    pub other: ContactTarget,
+
+    /// Normal of the contact, pointing towards the body
+    pub normal: Vector2,
+}
+
+
+/// How a body overlaps a trigger
+#[derive(Copy, Clone, Debug)]
+pub enum TriggerPhase {
+    /// The body started overlapping the trigger during the last step
+    Enter(Overlap),
+
+    /// The body was already overlapping the trigger before the last step
+    Stay(Overlap),
+
+    /// The body stopped overlapping the trigger during the last step, or either of them was removed
+    Exit,
+}
+
+
+/// A body overlapping a trigger after stepping a physics world
+#[derive(Copy, Clone, Debug)]
+pub struct TriggerEvent {
+    /// Handle of the trigger
+    pub trigger: usize,
+
+    /// Handle of the body
+    pub body: usize,
+
+    /// Whether the body entered, stayed in or exited the trigger
+    pub phase: TriggerPhase,
+}
+
+
+/// Owns static obstacles and moving bodies, and steps all bodies together
+pub struct PhysicsWorld<C> {
+    // The size of the cells used to find nearby objects
+    cell_size: Vector2,
+
+    // The acceleration of all bodies caused by gravity
+    gravity: Vector2,
+
+    // Obstacles that are not pushed by bodies, kinematic ones move with their own velocity
+    statics: SpatialGrid<StaticBody<C>>,
+
+    // Handles of the kinematic obstacles
+    kinematics: Vec<usize>,
+
+    // Bodies that move, none if removed
+    bodies: Vec<Option<RigidBody<C>>>,
+
+    // Regions that detect bodies without blocking them
+    triggers: SpatialGrid<C>,
+
+    // The triggers and bodies that overlapped after the last step, sorted
+    // Format: (trigger, body)
+    trigger_overlaps: Vec<(usize, usize)>,
+
+    // What happened to the triggers during the last step
+    trigger_events: Vec<TriggerEvent>,
+}
+
+
+/// An obstacle that is not pushed by bodies
+struct StaticBody<C> {
+    collider: C,
+    filter: CollisionFilter,
+    one_way: Option<Vector2>,
+
+    // Zero unless the obstacle is kinematic
+    velocity: Vector2,
+}
+
+
+impl<C> RigidBody<C> {
+    /// Create a body at rest
+    pub fn new(collider: C) -> RigidBody<C> {
+        RigidBody {
+            collider,
+            velocity: Vector2::zero(),
+            drag: Vector2::zero(),
+
+            mass: 1.0,
+            restitution: 0.0,
+            static_friction: 0.0,
+            dynamic_friction: 0.0,
+
+            gravity_scale: 1.0,
+
+            filter: CollisionFilter::default(),
+            contacts: Contacts::new(),
+
+            world_gravity: Vector2::zero(),
+        }
+    }
+
+
+    /// Set the velocity of the body
+    pub fn with_velocity(mut self, velocity: Vector2) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+
+    /// Set the drag of the body
+    pub fn with_drag(mut self, drag: Vector2) -> Self {
+        self.drag = drag;
+        self
+    }
+
+
+    /// Set the mass of the body
+    pub fn with_mass(mut self, mass: FloatType) -> Self {
+        self.mass = mass;
+        self
+    }
+
+
+    /// Set how much speed the body keeps when bouncing
+    pub fn with_restitution(mut self, restitution: FloatType) -> Self {
+        self.restitution = restitution;
+        self
+    }
+
+
+    /// Set how strongly the body sticks to and slides along surfaces
+    pub fn with_friction(mut self, static_friction: FloatType, dynamic_friction: FloatType) -> Self {
+        self.static_friction = static_friction;
+        self.dynamic_friction = dynamic_friction;
+        self
+    }
+
+
+    /// Set how strongly the world's gravity pulls on the body
+    pub fn with_gravity_scale(mut self, gravity_scale: FloatType) -> Self {
+        self.gravity_scale = gravity_scale;
+        self
+    }
+
+
+    /// Set which obstacles and other bodies the body collides with
+    pub fn with_filter(mut self, filter: CollisionFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+
+impl<C> PhysicsObject for RigidBody<C> where C: Clone + Bounded + Translate {
+    type CollisionBody = C;
+
+    fn get_position(&self) -> Vector2 {
+        self.collider.bounding_box().center()
+    }
+
+    fn set_position(&mut self, position: Vector2) {
+        let delta = position - self.get_position();
+        self.collider.translate(delta);
+    }
+
+    fn get_velocity(&self) -> Vector2 {
+        self.velocity
+    }
+
+    fn set_velocity(&mut self, velocity: Vector2) {
+        self.velocity = velocity;
+    }
+
+    fn get_drag(&self) -> Vector2 {
+        self.drag
+    }
+
+    fn set_drag(&mut self, drag: Vector2) {
+        self.drag = drag;
+    }
+
+    fn get_mass(&self) -> FloatType {
+        self.mass
+    }
+
+    fn get_restitution(&self) -> FloatType {
+        self.restitution
+    }
+
+    fn get_static_friction(&self) -> FloatType {
+        self.static_friction
+    }
+
+    fn get_dynamic_friction(&self) -> FloatType {
+        self.dynamic_friction
+    }
+
+    fn get_gravity(&self) -> Vector2 {
+        self.world_gravity * self.gravity_scale
+    }
+
+    fn get_collider<'a>(&'a self) -> &'a C {
+        &self.collider
+    }
+
+    fn get_collision_filter(&self) -> CollisionFilter {
+        self.filter
+    }
+}
+
+
+impl<C: Bounded> Bounded for StaticBody<C> {
+    fn bounding_box(&self) -> Rectangle {
+        self.collider.bounding_box()
+    }
+}
+
+
+impl<C> PhysicsWorld<C> where C: Body<C> + Bounded + Translate + Clone {
+    /// Create an empty world
+    ///
+    /// # Arguments
+    ///
+    /// * 'cell_size' - The size of the cells used to find nearby objects, should be a few times larger than a typical object
+    pub fn new(cell_size: Vector2) -> PhysicsWorld<C> {
+        PhysicsWorld {
+            cell_size,
+            gravity: Vector2::zero(),
+            statics: SpatialGrid::new(cell_size),
+            kinematics: Vec::new(),
+            bodies: Vec::new(),
+            triggers: SpatialGrid::new(cell_size),
+            trigger_overlaps: Vec::new(),
+            trigger_events: Vec::new(),
+        }
+    }
+
+
+    /// Set the acceleration of all bodies caused by gravity
+    pub fn set_gravity(&mut self, gravity: Vector2) {
+        self.gravity = gravity;
+
+        for slot in self.bodies.iter_mut() {
+            if let Some(ref mut body) = *slot {
+                body.world_gravity = gravity;
+            }
+        }
+    }
+
+
+    /// Return the acceleration of all bodies caused by gravity
+    pub fn get_gravity(&self) -> Vector2 {
+        self.gravity
+    }
+
+
+    /// Add an obstacle that never moves, returning its handle
+    pub fn add_static(&mut self, collider: C) -> usize {
+        self.statics.insert(StaticBody {
+            collider,
+            filter: CollisionFilter::default(),
+            one_way: None,
+            velocity: Vector2::zero(),
+        })
+    }
+
+
+    /// Add an obstacle that moves with its own velocity, but is not pushed by bodies, returning its handle.
+    /// Bodies standing on it are carried along.
+    pub fn add_kinematic(&mut self, collider: C, velocity: Vector2) -> usize {
+        let handle = self.statics.insert(StaticBody {
+            collider,
+            filter: CollisionFilter::default(),
+            one_way: None,
+            velocity,
+        });
+
+        self.kinematics.push(handle);
+        handle
+    }
+
+
+    /// Remove a static or kinematic obstacle, returning it
+    pub fn remove_static(&mut self, handle: usize) -> Option<C> {
+        self.kinematics.retain(|&kinematic| kinematic != handle);
+
+        match self.statics.remove(handle) {
+            Some(obstacle) => Some(obstacle.collider),
+            None => None
+        }
+    }
+
+
+    /// Return the obstacle with a handle
+    pub fn get_static(&self, handle: usize) -> Option<&C> {
+        match self.statics.get(handle) {
+            Some(obstacle) => Some(&obstacle.collider),
+            None => None
+        }
+    }
+
+
+    /// Set which bodies an obstacle blocks
+    pub fn set_static_filter(&mut self, handle: usize, filter: CollisionFilter) {
+        if let Some(mut obstacle) = self.statics.remove(handle) {
+            obstacle.filter = filter;
+            self.statics.set(handle, obstacle);
+        }
+    }
+
+
+    /// Set the velocity of a kinematic obstacle
+    pub fn set_kinematic_velocity(&mut self, handle: usize, velocity: Vector2) {
+        if !self.kinematics.contains(&handle) {
+            return;
+        }
+
+        if let Some(mut obstacle) = self.statics.remove(handle) {
+            obstacle.velocity = velocity;
+            self.statics.set(handle, obstacle);
+        }
+    }
+
+
+    /// Return the velocity of an obstacle, zero if it is static
+    pub fn get_static_velocity(&self, handle: usize) -> Option<Vector2> {
+        match self.statics.get(handle) {
+            Some(obstacle) => Some(obstacle.velocity),
+            None => None
+        }
+    }
+
+
+    /// Make an obstacle only block bodies moving against a direction, none to block from all directions
+    pub fn set_static_one_way(&mut self, handle: usize, direction: Option<Vector2>) {
+        if let Some(mut obstacle) = self.statics.remove(handle) {
+            obstacle.one_way = direction;
+            self.statics.set(handle, obstacle);
+        }
+    }
+
+
+    /// Add a body that moves, returning its handle
+    pub fn add_body(&mut self, mut body: RigidBody<C>) -> usize {
+        body.world_gravity = self.gravity;
+        self.bodies.push(Some(body));
+        self.bodies.len() - 1
+    }
+
+
+    /// Remove a body, returning it
+    pub fn remove_body(&mut self, handle: usize) -> Option<RigidBody<C>> {
+        match self.bodies.get_mut(handle) {
+            Some(slot) => slot.take(),
+            None => None
+        }
+    }
+
+
+    /// Return the body with a handle
+    pub fn get_body(&self, handle: usize) -> Option<&RigidBody<C>> {
+        match self.bodies.get(handle) {
+            Some(&Some(ref body)) => Some(body),
+            _ => None
+        }
+    }
+
+
+    /// Return the body with a handle
+    pub fn get_body_mut(&mut self, handle: usize) -> Option<&mut RigidBody<C>> {
+        match self.bodies.get_mut(handle) {
+            Some(&mut Some(ref mut body)) => Some(body),
+            _ => None
+        }
+    }
+
+
+    /// Return the handles of all bodies
+    pub fn get_body_handles(&self) -> Vec<usize> {
+        (0..self.bodies.len())
+            .filter(|&handle| self.bodies[handle].is_some())
+            .collect()
+    }
+
+
+    /// Add a region that detects bodies without blocking them, returning its handle
+    pub fn add_trigger(&mut self, collider: C) -> usize {
+        let handle = self.triggers.insert(collider);
+
+        // The handle could have belonged to a removed trigger, which no longer overlaps anything
+        self.trigger_overlaps.retain(|&(trigger, _)| trigger != handle);
+
+        handle
+    }
+
+
+    /// Remove a trigger, returning it
+    pub fn remove_trigger(&mut self, handle: usize) -> Option<C> {
+        self.triggers.remove(handle)
+    }
+
+
+    /// Return the trigger with a handle
+    pub fn get_trigger(&self, handle: usize) -> Option<&C> {
+        self.triggers.get(handle)
+    }
+
+
+    /// Move a collider along a path through the static and kinematic obstacles without changing the world,
+    /// returning the handle of the first obstacle it hits and the impact with it.
+    /// Bodies are not included, and kinematic obstacles are where they are at the start of the next step.
+    pub fn cast_shape(&self, collider: &C, path: Vector2) -> Option<(usize, Impact)> {
+        // Collides with every layer
+        self.cast_shape_filtered(collider, path, CollisionFilter::new(!0, !0))
+    }
+
+
+    /// Move a collider along a path through the static and kinematic obstacles it collides with
+    /// without changing the world, returning the handle of the first obstacle it hits and the impact with it.
+    /// Bodies are not included, and kinematic obstacles are where they are at the start of the next step.
+    ///
+    /// # Arguments
+    ///
+    /// * 'collider' - The collider to move
+    /// * 'path' - The movement of the collider
+    /// * 'filter' - Which obstacles the collider collides with, one-way obstacles only block it when moving against them
+    pub fn cast_shape_filtered(&self, collider: &C, path: Vector2, filter: CollisionFilter) -> Option<(usize, Impact)> {
+        self.cast_through_obstacles(collider, path, 0.0, filter)
+    }
+
+
+    /// Return how bodies entered, stayed in and exited the triggers during the last step
+    pub fn get_trigger_events(&self) -> &[TriggerEvent] {
+        &self.trigger_events
+    }
+
+
+    /// Move all bodies, returning every contact that happened along the way
+    pub fn tick(&mut self, dt: FloatType) -> Vec<ContactEvent> {
+        let carries = self.get_carries();
+
+        for slot in self.bodies.iter_mut() {
+            if let Some(ref mut body) = *slot {
+                body.contacts = Contacts::new();
+                body.world_gravity = self.gravity;
+                body.update_velocity(dt);
+            }
+        }
+
+        // Bodies standing on a moving obstacle move along with it, without it affecting their velocity
+        let mut offsets: Vec<Vector2> = carries.iter().map(|&carry| carry * dt).collect();
+
+        let body_events = self.sweep_bodies(dt, &mut offsets);
+
+        let mut events = self.move_bodies(dt, &offsets);
+        events.extend(body_events);
+        self.move_kinematics(dt);
+        events.extend(self.separate_bodies());
+
+        self.update_triggers();
+
+        events
+    }
+
+
+    /// Find the bodies overlapping each trigger and compare them with the previous step
+    fn update_triggers(&mut self) {
+        let mut events = Vec::new();
+        let mut overlaps = Vec::new();
+
+        for (handle, body) in self.bodies.iter().enumerate() {
+            let body = match *body {
+                Some(ref body) => body,
+                None => continue
+            };
+
+            for trigger in self.triggers.query_region(&body.collider.bounding_box()) {
+                let overlap = match self.triggers.get(trigger) {
+                    Some(collider) => body.collider.overlap(collider),
+                    None => None
+                };
+
+                if let Some(overlap) = overlap {
+                    let pair = (trigger, handle);
+
+                    let phase = if self.trigger_overlaps.binary_search(&pair).is_ok() {
+                        TriggerPhase::Stay(overlap)
+                    } else {
+                        TriggerPhase::Enter(overlap)
+                    };
+
+                    overlaps.push(pair);
+                    events.push(TriggerEvent {
+                        trigger,
+                        body: handle,
+                        phase,
+                    });
+                }
+            }
+        }
+
+        overlaps.sort();
+
+        // Pairs that no longer overlap, including those where either was removed
+        for &(trigger, body) in self.trigger_overlaps.iter() {
+            if overlaps.binary_search(&(trigger, body)).is_err() {
+                events.push(TriggerEvent {
+                    trigger,
+                    body,
+                    phase: TriggerPhase::Exit,
+                });
+            }
+        }
+
+        self.trigger_overlaps = overlaps;
+        self.trigger_events = events;
+    }
+
+
+    /// Return how fast the moving obstacle each body stood on during the last step moves along its surface, by handle.
+    /// Movement towards the body is already handled by the impact with the obstacle.
+    fn get_carries(&self) -> Vec<Vector2> {
+        let mut carries = vec![Vector2::zero(); self.bodies.len()];
+
+        for (handle, slot) in self.bodies.iter().enumerate() {
+            let body = match *slot {
+                Some(ref body) => body,
+                None => continue
+            };
+
+            for contact in body.contacts.iter().filter(|contact| contact.is_ground()) {
+                if let ContactTarget::Static(obstacle) = contact.target {
+                    if let Some(obstacle) = self.statics.get(obstacle) {
+                        let normal = contact.impact.normal;
+                        carries[handle] = obstacle.velocity - obstacle.velocity.dot(normal) * normal;
+                        break;
+                    }
+                }
+            }
+        }
+
+        carries
+    }
+
+
+    /// Move every body through the static obstacles
+    ///
+    /// # Arguments
+    ///
+    /// * 'offsets' - How far each body is moved in addition to its velocity, by handle
+    fn move_bodies(&mut self, dt: FloatType, offsets: &[Vector2]) -> Vec<ContactEvent> {
+        let mut events = Vec::new();
+        let statics = &self.statics;
+        let kinematics = &self.kinematics;
+
+        for (handle, slot) in self.bodies.iter_mut().enumerate() {
+            let body = match *slot {
+                Some(ref mut body) => body,
+                None => continue
+            };
+
+            let offset = offsets[handle];
+
+            // Only the obstacles the body can reach, including by sliding along the ones it hits first.
+            // Bouncing bodies turn around and can move back behind where they started,
+            // by at most the part of their path they keep when bouncing.
+            // Moving obstacles could come from anywhere.
+            let path = body.velocity * dt + offset;
+            let reach = body.restitution * path.length();
+
+            let mut region = body.collider.bounding_box().swept_region(path);
+            region.min -= Vector2::new(reach, reach);
+            region.max += Vector2::new(reach, reach);
+
+            let mut nearby = statics.query_region(&region);
+            nearby.extend(kinematics.iter().cloned());
+            nearby.sort();
+            nearby.dedup();
+
+            let mut obstacles: Vec<Obstacle<C>> = Vec::with_capacity(nearby.len());
+            for &index in nearby.iter() {
+                if let Some(obstacle) = statics.get(index) {
+                    obstacles.push(Obstacle {
+                        body: &obstacle.collider,
+                        filter: obstacle.filter,
+                        one_way: obstacle.one_way,
+                        velocity: obstacle.velocity,
+                    });
+                }
+            }
+
+            let contacts = body.update_position_with_offset(dt, offset, obstacles.as_slice());
+
+            for contact in contacts.into_iter() {
+                // Contacts are reported by their index in the nearby obstacles
+                let target = match contact.target {
+                    ContactTarget::Static(index) => ContactTarget::Static(nearby[index]),
+                    target => target
+                };
+
+                body.contacts.push(Contact {
+                    target,
+                    impact: contact.impact,
+                });
+
+                events.push(ContactEvent {
+                    body: handle,
+                    other: target,
+                    normal: contact.impact.normal,
+                });
+            }
+        }
+
+        events
+    }
+
+
+    /// Move the kinematic obstacles along their velocity
+    fn move_kinematics(&mut self, dt: FloatType) {
+        for &handle in self.kinematics.iter() {
+            if let Some(mut obstacle) = self.statics.remove(handle) {
+                obstacle.collider.translate(obstacle.velocity * dt);
+                self.statics.set(handle, obstacle);
+            }
+        }
+    }
+
+
+    /// Bounce off bodies that would hit each other while moving during the step,
+    /// so that fast bodies don't pass through each other
+    ///
+    /// # Arguments
+    ///
+    /// * 'offsets' - How far each body is moved in addition to its velocity, by handle.
+    /// Bodies that bounce are moved with their old velocity until the impact by changing their offset.
+    fn sweep_bodies(&mut self, dt: FloatType, offsets: &mut [Vector2]) -> Vec<ContactEvent> {
+        let mut events = Vec::new();
+
+        for (a, b, impact) in self.find_body_impacts(dt, offsets).into_iter() {
+            // Bodies with a lower handle are always in the left half
+            let (left, right) = self.bodies.split_at_mut(b);
+            let (body_a, body_b) = match (&mut left[a], &mut right[0]) {
+                (&mut Some(ref mut body_a), &mut Some(ref mut body_b)) => (body_a, body_b),
+                _ => continue
+            };
+
+            let (velocity_a, velocity_b) = (body_a.velocity, body_b.velocity);
+            bounce(body_a, body_b, impact.normal);
+
+            // Move with the old velocity until the impact and with the new one afterwards
+            offsets[a] += impact.time * dt * (velocity_a - body_a.velocity);
+            offsets[b] += impact.time * dt * (velocity_b - body_b.velocity);
+
+            record_contact(body_a, body_b, a, b, impact);
+
+            events.push(ContactEvent {
+                body: a,
+                other: ContactTarget::Body(b),
+                normal: impact.normal,
+            });
+
+            events.push(ContactEvent {
+                body: b,
+                other: ContactTarget::Body(a),
+                normal: -impact.normal,
+            });
+        }
+
+        events
+    }
+
+
+    /// Find the bodies that hit each other while moving during the step, before either of them is stopped by an obstacle.
+    /// Sorted by the time of impact.
+    /// Format: (first body, second body, impact of the first body with the second)
+    ///
+    /// # Arguments
+    ///
+    /// * 'offsets' - How far each body is moved in addition to its velocity, by handle
+    fn find_body_impacts(&self, dt: FloatType, offsets: &[Vector2]) -> Vec<(usize, usize, Impact)> {
+        let mut impacts = Vec::new();
+
+        // Sort the regions the bodies move through into a grid
+        let mut grid: SpatialGrid<Rectangle> = SpatialGrid::new(self.cell_size);
+        let mut handles = Vec::new();
+
+        for (handle, slot) in self.bodies.iter().enumerate() {
+            if let Some(ref body) = *slot {
+                grid.insert(body.collider.bounding_box().swept_region(body.velocity * dt + offsets[handle]));
+                handles.push(handle);
+            }
+        }
+
+        for first in 0..handles.len() {
+            let region = *grid.get(first).unwrap();
+
+            for second in grid.query_region(&region).into_iter() {
+                // Every pair is only handled once
+                if second <= first {
+                    continue;
+                }
+
+                let a = handles[first];
+                let b = handles[second];
+
+                let (body_a, body_b) = match (&self.bodies[a], &self.bodies[b]) {
+                    (&Some(ref body_a), &Some(ref body_b)) => (body_a, body_b),
+                    _ => continue
+                };
+
+                if !body_a.filter.collides_with(&body_b.filter) {
+                    continue;
+                }
+
+                // Bodies that already overlap are pushed apart after moving
+                if body_a.collider.intersects(&body_b.collider) {
+                    continue;
+                }
+
+                let path_a = body_a.velocity * dt + offsets[a];
+                let path_b = body_b.velocity * dt + offsets[b];
+
+                let impact = match body_a.collider.sweep_moving(path_a, &body_b.collider, path_b) {
+                    Some(impact) => impact,
+                    None => continue
+                };
+
+                // Bodies that are stopped by an obstacle first never reach each other
+                let duration = impact.time * dt;
+                let blocked_a = self.cast_through_obstacles(&body_a.collider, impact.time * path_a, duration, body_a.filter);
+                let blocked_b = self.cast_through_obstacles(&body_b.collider, impact.time * path_b, duration, body_b.filter);
+
+                if blocked_a.is_none() && blocked_b.is_none() {
+                    impacts.push((a, b, impact));
+                }
+            }
+        }
+
+        impacts.sort_by(|&(_, _, ref first), &(_, _, ref second)| {
+            first.time.partial_cmp(&second.time).unwrap_or(Ordering::Equal)
+        });
+
+        impacts
+    }
+
+
+    /// Return the handle of the first static or kinematic obstacle a collider hits while moving along a path,
+    /// and the impact with it
+    ///
+    /// # Arguments
+    ///
+    /// * 'collider' - The collider to move
+    /// * 'path' - The movement of the collider
+    /// * 'duration' - How long the movement takes, kinematic obstacles move along during this time
+    /// * 'filter' - Which obstacles the collider collides with
+    fn cast_through_obstacles(&self, collider: &C, path: Vector2, duration: FloatType, filter: CollisionFilter) -> Option<(usize, Impact)> {
+        let mut first: Option<(usize, Impact)> = None;
+
+        // Moving obstacles could come from anywhere
+        let mut nearby = self.statics.query_region(&collider.bounding_box().swept_region(path));
+        nearby.extend(self.kinematics.iter().cloned());
+        nearby.sort();
+        nearby.dedup();
+
+        for handle in nearby.into_iter() {
+            let obstacle = match self.statics.get(handle) {
+                Some(obstacle) => obstacle,
+                None => continue
+            };
+
+            if !obstacle.filter.collides_with(&filter) {
+                continue;
+            }
+
+            // Move relative to the obstacle, as if it stayed where it started
+            let relative_path = path - obstacle.velocity * duration;
+
+            if let Some(impact) = collider.sweep(relative_path, &obstacle.collider) {
+                let blocks = Obstacle {
+                    body: &obstacle.collider,
+                    filter: obstacle.filter,
+                    one_way: obstacle.one_way,
+                    velocity: obstacle.velocity,
+                }.blocks(relative_path, impact.normal);
+
+                if !blocks {
+                    continue;
+                }
+
+                let is_first = match first {
+                    Some((_, ref first)) => impact.time < first.time,
+                    None => true,
+                };
+
+                if is_first {
+                    first = Some((handle, impact));
+                }
+            }
+        }
+
+        first
+    }
+
+
+    /// Push bodies that overlap each other apart
+    fn separate_bodies(&mut self) -> Vec<ContactEvent> {
+        let mut events = Vec::new();
+
+        // Sort the bodies into a grid to only test the ones that are close to each other
+        let mut grid: SpatialGrid<Rectangle> = SpatialGrid::new(self.cell_size);
+        let mut handles = Vec::new();
+
+        for (handle, slot) in self.bodies.iter().enumerate() {
+            if let Some(ref body) = *slot {
+                grid.insert(body.collider.bounding_box());
+                handles.push(handle);
+            }
+        }
+
+        for first in 0..handles.len() {
+            let bounds = *grid.get(first).unwrap();
+
+            for second in grid.query_region(&bounds).into_iter() {
+                // Every pair is only handled once
+                if second <= first {
+                    continue;
+                }
+
+                let a = handles[first];
+                let b = handles[second];
+
+                // Bodies with a lower handle are always in the left half
+                let (left, right) = self.bodies.split_at_mut(b);
+                let (body_a, body_b) = match (&mut left[a], &mut right[0]) {
+                    (&mut Some(ref mut body_a), &mut Some(ref mut body_b)) => (body_a, body_b),
+                    _ => continue
+                };
+
+                if !body_a.filter.collides_with(&body_b.filter) {
+                    continue;
+                }
+
+                if let Some(overlap) = body_a.collider.overlap(&body_b.collider) {
+                    let normal = overlap.resolve.normal();
+
+                    let inverse_mass_a = body_a.get_inverse_mass();
+                    let inverse_mass_b = body_b.get_inverse_mass();
+                    let total_inverse_mass = inverse_mass_a + inverse_mass_b;
+
+                    // Lighter bodies move further, bodies with infinite mass don't move at all
+                    if total_inverse_mass > 0.0 {
+                        body_a.collider.translate(inverse_mass_a / total_inverse_mass * overlap.resolve);
+                        body_b.collider.translate(-inverse_mass_b / total_inverse_mass * overlap.resolve);
+                    }
+
+                    bounce(body_a, body_b, normal);
+                    record_contact(body_a, body_b, a, b, Impact { time: 0.0, normal });
+
+                    events.push(ContactEvent {
+                        body: a,
+                        other: ContactTarget::Body(b),
+                        normal,
+                    });
+
+                    events.push(ContactEvent {
+                        body: b,
+                        other: ContactTarget::Body(a),
+                        normal: -normal,
+                    });
+                }
+            }
+        }
+
+        events
+    }
+}
+
+
+/// Add a contact between two bodies to both of them
+///
+/// # Arguments
+///
+/// * 'a' - The handle of the first body
+/// * 'b' - The handle of the second body
+/// * 'impact' - The impact of the first body with the second, with the normal pointing towards the first body
+fn record_contact<C>(body_a: &mut RigidBody<C>, body_b: &mut RigidBody<C>, a: usize, b: usize, impact: Impact) {
+    body_a.contacts.push(Contact {
+        target: ContactTarget::Body(b),
+        impact,
+    });
+
+    body_b.contacts.push(Contact {
+        target: ContactTarget::Body(a),
+        impact: impact.inverse(),
+    });
+}
+
+
+/// Bounce two bodies off each other if they are moving into each other
+///
+/// # Arguments
+///
+/// * 'normal' - The normal of the contact, pointing towards the first body
+fn bounce<C>(body_a: &mut RigidBody<C>, body_b: &mut RigidBody<C>, normal: Vector2) where C: Clone + Bounded + Translate {
+    let inverse_mass_a = body_a.get_inverse_mass();
+    let inverse_mass_b = body_b.get_inverse_mass();
+    let total_inverse_mass = inverse_mass_a + inverse_mass_b;
+
+    // Bodies with infinite mass can't be pushed
+    if total_inverse_mass == 0.0 {
+        return;
+    }
+
+    let relative_velocity = body_a.velocity - body_b.velocity;
+    let approach = relative_velocity.dot(normal);
+
+    if approach >= 0.0 {
+        return;
+    }
+
+    let restitution = min!(body_a.restitution, body_b.restitution);
+    let normal_impulse = -(1.0 + restitution) * approach;
+
+    let tangent_velocity = relative_velocity - approach * normal;
+    let friction = friction(
+        tangent_velocity,
+        normal_impulse,
+        (body_a.static_friction * body_b.static_friction).sqrt(),
+        (body_a.dynamic_friction * body_b.dynamic_friction).sqrt()
+    );
+
+    // The change in relative velocity, divided between the bodies by their mass
+    let change = normal_impulse * normal + friction;
+    body_a.velocity += inverse_mass_a / total_inverse_mass * change;
+    body_b.velocity -= inverse_mass_b / total_inverse_mass * change;
+}
@@ -0,0 +1,168 @@
+use super::view::View;
+
+use ::{Vector2, FloatType, Transform};
+
+
+/// A view that can be moved, zoomed and rotated, and that keeps the proportions of the viewport
+#[derive(Copy, Clone, Debug)]
+pub struct Camera {
+    /// The point in the middle of the view
+    pub center: Vector2,
+
+    /// How much of the world is visible vertically, before zooming
+    pub height: FloatType,
+
+    /// How much larger everything appears, 2 shows half as much of the world
+    pub zoom: FloatType,
+
+    /// The counter-clockwise rotation of the camera, in radians
+    pub rotation: FloatType,
+
+    /// The width of the viewport divided by its height
+    pub aspect: FloatType,
+
+    // How strongly and for how much longer the camera shakes, in seconds
+    shake_intensity: FloatType,
+    shake_duration: FloatType,
+    shake_remaining: FloatType,
+
+    // The time since the camera started shaking, used to calculate the shake offset
+    shake_time: FloatType,
+}
+
+
+// How often per second the camera changes direction while shaking
+const SHAKE_FREQUENCY: FloatType = 25.0;
+
+
+impl Camera {
+    /// Create a new camera
+    ///
+    /// # Arguments
+    ///
+    /// * 'center' - The point in the middle of the view
+    /// * 'height' - How much of the world is visible vertically
+    pub fn new(center: Vector2, height: FloatType) -> Camera {
+        Camera {
+            center,
+            height,
+            zoom: 1.0,
+            rotation: 0.0,
+            aspect: 1.0,
+
+            shake_intensity: 0.0,
+            shake_duration: 0.0,
+            shake_remaining: 0.0,
+            shake_time: 0.0,
+        }
+    }
+
+
+    /// Set how much larger everything appears
+    pub fn with_zoom(mut self, zoom: FloatType) -> Self {
+        self.zoom = zoom;
+        self
+    }
+
+
+    /// Set the counter-clockwise rotation of the camera, in radians
+    pub fn with_rotation(mut self, rotation: FloatType) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+
+    /// Set the width of the viewport divided by its height
+    pub fn with_aspect(mut self, aspect: FloatType) -> Self {
+        self.aspect = aspect;
+        self
+    }
+
+
+    /// Match the proportions of a viewport with a size in pixels
+    pub fn set_viewport_size(&mut self, width: u32, height: u32) {
+        if height > 0 {
+            self.aspect = width as FloatType / height as FloatType;
+        }
+    }
+
+
+    /// Return how much of the world is visible, before rotating
+    pub fn get_size(&self) -> Vector2 {
+        let height = self.height / self.zoom;
+        Vector2::new(height * self.aspect, height)
+    }
+
+
+    /// Move the center smoothly towards a target, covering the same fraction of the distance every second
+    ///
+    /// # Arguments
+    ///
+    /// * 'target' - The point to follow
+    /// * 'stiffness' - How quickly the camera catches up, higher values follow more closely
+    /// * 'dt' - The time since the last update, in seconds
+    pub fn follow(&mut self, target: Vector2, stiffness: FloatType, dt: FloatType) {
+        // Exponential decay of the distance is independent of the frame rate
+        let fraction = 1.0 - (-stiffness * dt).exp();
+        self.center += fraction * (target - self.center);
+    }
+
+
+    /// Start shaking the camera, fading out over time
+    ///
+    /// # Arguments
+    ///
+    /// * 'intensity' - How far the camera moves from its center at most, in world units
+    /// * 'duration' - How long the camera shakes, in seconds
+    pub fn shake(&mut self, intensity: FloatType, duration: FloatType) {
+        self.shake_intensity = intensity;
+        self.shake_duration = duration;
+        self.shake_remaining = duration;
+        self.shake_time = 0.0;
+    }
+
+
+    /// Return true if the camera is still shaking
+    pub fn is_shaking(&self) -> bool {
+        self.shake_remaining > 0.0
+    }
+
+
+    /// Advance the shaking of the camera
+    pub fn update(&mut self, dt: FloatType) {
+        if self.is_shaking() {
+            self.shake_time += dt;
+            self.shake_remaining = max!(self.shake_remaining - dt, 0.0);
+        }
+    }
+
+
+    /// Return how far the camera is moved from its center by shaking
+    pub fn get_shake_offset(&self) -> Vector2 {
+        if !self.is_shaking() || self.shake_duration <= 0.0 {
+            return Vector2::zero();
+        }
+
+        // Fade out linearly
+        let strength = self.shake_intensity * self.shake_remaining / self.shake_duration;
+
+        // Waves of unrelated frequencies look random, but are repeatable
+        let phase = self.shake_time * SHAKE_FREQUENCY;
+        let offset = Vector2::new(
+            (phase * 1.13).sin() * (phase * 0.71).cos(),
+            (phase * 0.97).cos() * (phase * 1.31).sin(),
+        );
+
+        strength * offset
+    }
+}
+
+
+impl View for Camera {
+    fn get_transform(&self) -> Transform {
+        let translation = -(self.center + self.get_shake_offset());
+
+        // Rotating the camera turns the world the other way
+        Transform::scale(2.0 / self.get_size()) * Transform::rotate(-self.rotation) * Transform::translate(translation)
+    }
+}
@@ -15,9 +15,21 @@ pub use self::render_batch::RenderBatch;
 
 pub mod view;
 
+mod camera;
+pub use self::camera::Camera;
+
 pub mod texture;
 
 mod renderer;
 pub use self::renderer::Renderer;
 
+mod opengl_renderer;
+pub use self::opengl_renderer::OpenGLRenderer;
+
+mod recording;
+pub use self::recording::{RecordingRenderer, RenderCommand, RecordedBatch, RecordedMesh};
+
+mod rasterizer;
+pub use self::rasterizer::Rasterizer;
+
 
@@ -0,0 +1,382 @@
+
+use super::Vertex;
+use super::{RenderBatch, Renderer};
+use super::recording::RecordedBatch;
+use super::texture::{SoftwareTexture, TextureFilter};
+
+use ::images::Image;
+use ::Vector2;
+
+use std::cmp::Ordering;
+
+
+/// Draws render batches into an image in main memory.
+/// Produces the same result as the OpenGL renderer, without requiring a window.
+pub struct Rasterizer {
+    width: u32,
+    height: u32,
+
+    // Color of every pixel in RGBA format, row by row starting from the top
+    color_buffer: Vec<u8>,
+
+    // Depth of every pixel, in the range [0, 1]
+    depth_buffer: Vec<f32>,
+
+    clear_color: [f32; 4],
+
+    // The region that is drawn to: left, bottom, width and height in pixels
+    viewport: (i64, i64, i64, i64),
+}
+
+
+/// A vertex transformed into window coordinates
+#[derive(Copy, Clone)]
+struct WindowVertex {
+    x: f64,
+    y: f64,
+    depth: f32,
+
+    color: [f32; 4],
+    tex_coord: [f32; 2],
+}
+
+
+impl Rasterizer {
+    /// Create a new rasterizer with a certain size, in pixels.
+    /// Only textures kept in main memory can be sampled, see 'SoftwareTextureScope'.
+    pub fn new(width: u32, height: u32) -> Rasterizer {
+        let pixel_count = (width * height) as usize;
+
+        Rasterizer {
+            width,
+            height,
+
+            color_buffer: vec![0; 4 * pixel_count],
+            depth_buffer: vec![1.0; pixel_count],
+
+            clear_color: [0.0; 4],
+
+            viewport: (0, 0, width as i64, height as i64),
+        }
+    }
+
+
+    /// Return the size of the image, in pixels
+    pub fn get_size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+
+    /// Draw a recorded render batch
+    pub fn draw_recorded_batch(&mut self, batch: &RecordedBatch) {
+        // Textures that are not kept in main memory are sampled as white
+        let textures: Vec<Option<SoftwareTexture>> = batch.meshes.iter()
+            .map(|mesh| mesh.texture.get_software_texture())
+            .collect();
+
+        // Collect the triangles of every mesh
+        let mut triangles: Vec<(usize, [&Vertex; 3])> = Vec::new();
+
+        for (mesh_index, mesh) in batch.meshes.iter().enumerate() {
+            for indices in mesh.indices.chunks(3) {
+                if indices.len() == 3 {
+                    triangles.push((mesh_index, [
+                        &mesh.vertices[indices[0] as usize],
+                        &mesh.vertices[indices[1] as usize],
+                        &mesh.vertices[indices[2] as usize],
+                    ]));
+                }
+            }
+        }
+
+        // Draw from the bottom layer to the top, so that transparent layers blend with the ones below.
+        // Within a layer the triangles keep the order they were drawn in.
+        triangles.sort_by(|a, b| {
+            a.1[0].position[2].partial_cmp(&b.1[0].position[2]).unwrap_or(Ordering::Equal)
+        });
+
+        for &(mesh_index, ref vertices) in triangles.iter() {
+            let window_vertices = [
+                self.to_window(vertices[0], batch),
+                self.to_window(vertices[1], batch),
+                self.to_window(vertices[2], batch),
+            ];
+
+            self.fill_triangle(window_vertices, textures[mesh_index].as_ref());
+        }
+    }
+
+
+    /// Return the image that has been drawn
+    pub fn get_image(&self) -> Image {
+        Image::from_rgba(self.width, self.height, self.color_buffer.clone())
+    }
+
+
+    // Transform a vertex into window coordinates, the same way 'shader.vert' does
+    fn to_window(&self, vertex: &Vertex, batch: &RecordedBatch) -> WindowVertex {
+        let ndc = batch.transform.transform_point(Vector2::new(vertex.position[0] as f64, vertex.position[1] as f64));
+        let (ndc_x, ndc_y) = (ndc.x, ndc.y);
+        let ndc_z = 0.99 - 0.98 * vertex.position[2] / batch.layer_count as f32;
+
+        // Map into the viewport, whose origin is in the bottom left corner
+        let (left, bottom, width, height) = self.viewport;
+        let x = left as f64 + (ndc_x + 1.0) / 2.0 * width as f64;
+        let y = bottom as f64 + (ndc_y + 1.0) / 2.0 * height as f64;
+
+        WindowVertex {
+            x,
+            y: self.height as f64 - y,
+            depth: (ndc_z + 1.0) / 2.0,
+
+            color: vertex.color,
+            tex_coord: vertex.tex_coord,
+        }
+    }
+
+
+    // Fill all pixels whose centers lie within a triangle
+    fn fill_triangle(&mut self, mut vertices: [WindowVertex; 3], texture: Option<&SoftwareTexture>) {
+        let mut area = edge_function(&vertices[0], &vertices[1], vertices[2].x, vertices[2].y);
+
+        // There is no face culling, make sure all triangles are wound the same way
+        if area < 0.0 {
+            vertices.swap(1, 2);
+            area = -area;
+        }
+
+        if area == 0.0 {
+            return;
+        }
+
+        let a = vertices[0];
+        let b = vertices[1];
+        let c = vertices[2];
+
+        // Only visit the pixels within both the triangle's bounds and the viewport
+        let (left, top, right, bottom) = self.viewport_bounds();
+
+        let min_x = max!(min!(a.x, min!(b.x, c.x)).floor(), left) as u32;
+        let min_y = max!(min!(a.y, min!(b.y, c.y)).floor(), top) as u32;
+        let max_x = min!(max!(a.x, max!(b.x, c.x)).ceil(), right) as u32;
+        let max_y = min!(max!(a.y, max!(b.y, c.y)).ceil(), bottom) as u32;
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let px = x as f64 + 0.5;
+                let py = y as f64 + 0.5;
+
+                // Weights of each vertex
+                let wa = edge_function(&b, &c, px, py);
+                let wb = edge_function(&c, &a, px, py);
+                let wc = edge_function(&a, &b, px, py);
+
+                // Pixels exactly on an edge belong to only one of the triangles sharing it
+                if !covers(wa, &b, &c) || !covers(wb, &c, &a) || !covers(wc, &a, &b) {
+                    continue;
+                }
+
+                let weights = [(wa / area) as f32, (wb / area) as f32, (wc / area) as f32];
+
+                let depth = interpolate(weights, a.depth, b.depth, c.depth);
+
+                let mut color = [0.0; 4];
+                for i in 0..4 {
+                    color[i] = interpolate(weights, a.color[i], b.color[i], c.color[i]);
+                }
+
+                let u = interpolate(weights, a.tex_coord[0], b.tex_coord[0], c.tex_coord[0]);
+                let v = interpolate(weights, a.tex_coord[1], b.tex_coord[1], c.tex_coord[1]);
+
+                if let Some(texture) = texture {
+                    let texel = sample(texture, u, v);
+                    for i in 0..4 {
+                        color[i] *= texel[i];
+                    }
+                }
+
+                self.write_fragment(x, y, depth, color);
+            }
+        }
+    }
+
+
+    // Return the pixels covered by both the viewport and the image: left, top, right and bottom
+    fn viewport_bounds(&self) -> (f64, f64, f64, f64) {
+        let (left, bottom, width, height) = self.viewport;
+        let image_height = self.height as i64;
+
+        (
+            max!(left, 0) as f64,
+            max!(image_height - (bottom + height), 0) as f64,
+            min!(left + width, self.width as i64) as f64,
+            min!(image_height - bottom, image_height) as f64,
+        )
+    }
+
+
+    // Depth test and blend a fragment into the image, the same way 'shader.frag' and the renderer does
+    fn write_fragment(&mut self, x: u32, y: u32, depth: f32, color: [f32; 4]) {
+        // Fully transparent fragments are discarded
+        if color[3] == 0.0 {
+            return;
+        }
+
+        let index = (x + y * self.width) as usize;
+
+        // Only draw fragments closer than the previous
+        if !(depth < self.depth_buffer[index]) {
+            return;
+        }
+
+        self.depth_buffer[index] = depth;
+
+        // Blend with the alpha of the source
+        let pixel = &mut self.color_buffer[4 * index..4 * index + 4];
+        let alpha = color[3];
+
+        let mut blended = [0.0; 4];
+        for i in 0..4 {
+            let destination = pixel[i] as f32 / 255.0;
+            blended[i] = color[i] * alpha + destination * (1.0 - alpha);
+        }
+
+        pixel.copy_from_slice(&to_bytes(blended));
+    }
+}
+
+
+impl Renderer for Rasterizer {
+    fn set_clear_color(&mut self, color: [f32; 4]) {
+        self.clear_color = color;
+    }
+
+
+    fn set_viewport(&mut self, x: i32, y: i32, width: u32, height: u32) {
+        self.viewport = (x as i64, y as i64, width as i64, height as i64);
+    }
+
+
+    fn clear(&mut self) {
+        let color = to_bytes(self.clear_color);
+
+        for pixel in self.color_buffer.chunks_mut(4) {
+            pixel.copy_from_slice(&color);
+        }
+
+        self.clear_depth();
+    }
+
+
+    fn clear_depth(&mut self) {
+        for depth in self.depth_buffer.iter_mut() {
+            *depth = 1.0;
+        }
+    }
+
+
+    fn submit_batch(&mut self, batch: &RenderBatch) {
+        self.draw_recorded_batch(&RecordedBatch::from(batch));
+    }
+}
+
+
+// Twice the signed area of the triangle formed by an edge and a point
+fn edge_function(a: &WindowVertex, b: &WindowVertex, x: f64, y: f64) -> f64 {
+    (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x)
+}
+
+
+// Returns true if a pixel with a weight relative to an edge is covered by the triangle.
+// Pixels exactly on an edge are only covered by top and left edges.
+fn covers(weight: f64, a: &WindowVertex, b: &WindowVertex) -> bool {
+    if weight > 0.0 {
+        true
+    } else if weight == 0.0 {
+        let dx = b.x - a.x;
+        let dy = b.y - a.y;
+
+        let top = dy == 0.0 && dx > 0.0;
+        let left = dy < 0.0;
+
+        top || left
+    } else {
+        false
+    }
+}
+
+
+// Interpolate a value between the vertices of a triangle
+fn interpolate(weights: [f32; 3], a: f32, b: f32, c: f32) -> f32 {
+    weights[0] * a + weights[1] * b + weights[2] * c
+}
+
+
+// Sample the color of a texture at some texture coordinates, clamping at the edges
+fn sample(texture: &SoftwareTexture, u: f32, v: f32) -> [f32; 4] {
+    let width = texture.width as f32;
+    let height = texture.height as f32;
+
+    match texture.filter {
+        TextureFilter::Nearest => {
+            texel(texture, (u * width).floor() as i64, (v * height).floor() as i64)
+        }
+
+        TextureFilter::Linear => {
+            // Position relative to the texel centers
+            let x = u * width - 0.5;
+            let y = v * height - 0.5;
+
+            let x0 = x.floor();
+            let y0 = y.floor();
+
+            let fx = x - x0;
+            let fy = y - y0;
+
+            let x0 = x0 as i64;
+            let y0 = y0 as i64;
+
+            let top_left = texel(texture, x0, y0);
+            let top_right = texel(texture, x0 + 1, y0);
+            let bottom_left = texel(texture, x0, y0 + 1);
+            let bottom_right = texel(texture, x0 + 1, y0 + 1);
+
+            let mut color = [0.0; 4];
+            for i in 0..4 {
+                let top = top_left[i] * (1.0 - fx) + top_right[i] * fx;
+                let bottom = bottom_left[i] * (1.0 - fx) + bottom_right[i] * fx;
+
+                color[i] = top * (1.0 - fy) + bottom * fy;
+            }
+
+            color
+        }
+    }
+}
+
+
+// Return the color of a single texel, clamping at the edges
+fn texel(texture: &SoftwareTexture, x: i64, y: i64) -> [f32; 4] {
+    let x = min!(max!(x, 0), texture.width as i64 - 1) as usize;
+    let y = min!(max!(y, 0), texture.height as i64 - 1) as usize;
+
+    let index = 4 * (x + y * texture.width as usize);
+    let pixel = &texture.pixels[index..index + 4];
+
+    [
+        pixel[0] as f32 / 255.0,
+        pixel[1] as f32 / 255.0,
+        pixel[2] as f32 / 255.0,
+        pixel[3] as f32 / 255.0,
+    ]
+}
+
+
+// Convert a color into bytes
+fn to_bytes(color: [f32; 4]) -> [u8; 4] {
+    let mut bytes = [0; 4];
+    for i in 0..4 {
+        bytes[i] = (min!(max!(color[i], 0.0), 1.0) * 255.0).round() as u8;
+    }
+    bytes
+}
@@ -0,0 +1,132 @@
+
+use super::Vertex;
+use super::texture::Texture;
+use super::{RenderBatch, Renderer};
+
+use ::{Transform};
+
+
+/// A command received by a renderer, in the order they were received
+#[derive(Debug, Clone)]
+pub enum RenderCommand {
+    /// All render buffers were cleared
+    Clear,
+
+    /// The depth buffer was cleared
+    ClearDepth,
+
+    /// The color used to clear the screen was changed
+    SetClearColor([f32; 4]),
+
+    /// The region of the screen that is rendered to was changed
+    SetViewport { x: i32, y: i32, width: u32, height: u32 },
+
+    /// A render batch was submitted
+    SubmitBatch(RecordedBatch),
+}
+
+
+/// A renderer that records all commands instead of drawing anything
+pub struct RecordingRenderer {
+    commands: Vec<RenderCommand>,
+}
+
+
+/// A copy of the contents of a render batch at the time it was submitted
+#[derive(Debug, Clone)]
+pub struct RecordedBatch {
+    /// One mesh for every texture used, in the order they were first used
+    pub meshes: Vec<RecordedMesh>,
+
+    /// The transform of the batch's view, from world coordinates to the range [-1, 1]
+    pub transform: Transform,
+
+    /// The amount of layers in the batch
+    pub layer_count: u32,
+}
+
+
+/// Vertices and indices drawn with a texture
+#[derive(Debug, Clone)]
+pub struct RecordedMesh {
+    pub texture: Texture,
+
+    pub vertices: Vec<Vertex>,
+    pub indices: Vec<u32>,
+}
+
+
+impl RecordingRenderer {
+    /// Create a renderer without any recorded commands
+    pub fn new() -> RecordingRenderer {
+        RecordingRenderer {
+            commands: Vec::new()
+        }
+    }
+
+
+    /// Return all commands, in the order they were received
+    pub fn get_commands(&self) -> &[RenderCommand] {
+        &self.commands
+    }
+
+
+    /// Remove all commands, returning them in the order they were received
+    pub fn take_commands(&mut self) -> Vec<RenderCommand> {
+        ::std::mem::replace(&mut self.commands, Vec::new())
+    }
+}
+
+
+impl Renderer for RecordingRenderer {
+    fn set_clear_color(&mut self, color: [f32; 4]) {
+        self.commands.push(RenderCommand::SetClearColor(color));
+    }
+
+    fn set_viewport(&mut self, x: i32, y: i32, width: u32, height: u32) {
+        self.commands.push(RenderCommand::SetViewport { x, y, width, height });
+    }
+
+    fn clear(&mut self) {
+        self.commands.push(RenderCommand::Clear);
+    }
+
+    fn clear_depth(&mut self) {
+        self.commands.push(RenderCommand::ClearDepth);
+    }
+
+    fn submit_batch(&mut self, batch: &RenderBatch) {
+        self.commands.push(RenderCommand::SubmitBatch(RecordedBatch::from(batch)));
+    }
+}
+
+
+impl<'a> From<&'a RenderBatch> for RecordedBatch {
+    fn from(batch: &'a RenderBatch) -> Self {
+        let transform = batch.view.get_transform();
+
+        // Keep the order the textures were first used in
+        let mut textures: Vec<(&Texture, &usize)> = batch.mesh_indices.iter().collect();
+        textures.sort_by_key(|&(_, &index)| index);
+
+        let mut meshes = Vec::with_capacity(textures.len());
+
+        for (texture, &index) in textures.into_iter() {
+            let mesh = &batch.meshes[index];
+
+            if mesh.indices.len() > 0 {
+                meshes.push(RecordedMesh {
+                    texture: *texture,
+                    vertices: mesh.vertices.clone(),
+                    indices: mesh.indices.clone(),
+                });
+            }
+        }
+
+        RecordedBatch {
+            meshes,
+            transform,
+            layer_count: batch.layer_count,
+        }
+    }
+}
@@ -4,12 +4,12 @@ use super::texture::Texture;
 
 use super::mesh::Mesh;
 
-use ::shape::{RenderShape, Rectangle, Line, Triangle};
+use ::shape::{RenderShape, Rectangle, OrientedRectangle, Line, Triangle, Circle, Polygon};
 
 use std::f32::consts::PI;
 use std::collections::HashMap;
 
-use ::{FloatType, Vector2};
+use ::{FloatType, Vector2, Transform};
 
 pub struct RenderBatch {
     pub(super) mesh_indices: HashMap<Texture, usize>,
@@ -23,6 +23,9 @@ pub struct RenderBatch {
     default_texture: Texture,
 
     pub(super) view: Box<View>,
+
+    // The combined transforms that were pushed, the last one is applied to all shapes
+    transforms: Vec<Transform>,
 }
 
 
@@ -46,6 +49,8 @@ impl RenderBatch {
             default_texture,
 
             view: Box::new(Rectangle::default()),
+
+            transforms: Vec::new(),
         }
     }
 
@@ -69,6 +74,7 @@ impl RenderBatch {
         self.current_color = [1.0; 4];
         self.layer_count = 0;
         self.view = Box::new(Rectangle::default());
+        self.transforms.clear();
     }
 
 
@@ -80,18 +86,9 @@ impl RenderBatch {
     }
 
 
-    /// Return the bounds of the view
+    /// Return the smallest rectangle that contains everything that is visible through the view
     pub fn get_view_bounds(&self) -> Rectangle {
-        let mut bottom_left = self.view.ndc_to_world(Vector2::new(-1.0, -1.0));
-        let mut top_right = self.view.ndc_to_world(Vector2::new(1.0, 1.0));
-
-        if bottom_left.y > top_right.y {
-            let tmp = bottom_left.y;
-            bottom_left.y = top_right.y;
-            top_right.y = tmp;
-        }
-
-        Rectangle { min: bottom_left, max: top_right }
+        self.view.get_bounds()
     }
 
 
@@ -124,6 +121,46 @@ impl RenderBatch {
     }
 
 
+    /// Apply a transform to all shapes drawn until it is popped, on top of the transforms pushed before it
+    pub fn push_transform(&mut self, transform: Transform) {
+        let current = self.get_transform();
+        self.transforms.push(current * transform);
+    }
+
+
+    /// Stop applying the transform that was pushed last
+    pub fn pop_transform(&mut self) {
+        self.transforms.pop();
+    }
+
+
+    /// Return the transform applied to all shapes being drawn
+    pub fn get_transform(&self) -> Transform {
+        match self.transforms.last() {
+            Some(&transform) => transform,
+            None => Transform::identity()
+        }
+    }
+
+
+    /// Apply the current transform to the vertices added to the current mesh since an index
+    fn transform_vertices(&mut self, index_start: u32) {
+        let transform = match self.transforms.last() {
+            Some(&transform) => transform,
+            None => return
+        };
+
+        let mesh = &mut self.meshes[self.current_mesh];
+
+        for vertex in mesh.vertices[index_start as usize..].iter_mut() {
+            let position = transform.transform_point(Vector2::new(vertex.position[0] as FloatType, vertex.position[1] as FloatType));
+
+            vertex.position[0] = position.x as f32;
+            vertex.position[1] = position.y as f32;
+        }
+    }
+
+
     /// Get the z-value of the next layer and increase the layer count
     fn advance_layer(&mut self) -> f32 {
         let z = self.layer_count as f32;
@@ -170,6 +207,8 @@ impl RenderBatch {
             // Increase angle
             angle += delta_angle;
         }
+
+        self.transform_vertices(index_start);
     }
 
     /// Draw a circle with automatic number of segments
@@ -261,6 +300,8 @@ impl RenderShape for RenderBatch {
         mesh.indices.push(index_start + 2);
         mesh.indices.push(index_start + 3);
         mesh.indices.push(index_start + 0);
+
+        self.transform_vertices(index_start);
     }
 
     fn fill_rectangle(&mut self, rect: &Rectangle) {
@@ -303,6 +344,8 @@ impl RenderShape for RenderBatch {
         mesh.indices.push(index_start + 2);
         mesh.indices.push(index_start + 3);
         mesh.indices.push(index_start + 0);
+
+        self.transform_vertices(index_start);
     }
 
     fn draw_rectangle(&mut self, rect: &Rectangle, line_width: FloatType) {
@@ -317,6 +360,42 @@ impl RenderShape for RenderBatch {
         self.draw_line(&Line::new(Vector2::new(x0, y1), Vector2::new(x0, y0)), line_width);
     }
 
+    fn fill_oriented_rectangle(&mut self, rect: &OrientedRectangle) {
+        let z = self.advance_layer();
+
+        // Get current mesh
+        let mesh = &mut self.meshes[self.current_mesh];
+        let index_start: u32 = mesh.vertices.len() as u32;
+
+        // Textures rotate along with the rectangle
+        let tex_coords = [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+
+        for (corner, &tex_coord) in rect.corners().iter().zip(tex_coords.iter()) {
+            mesh.vertices.push(
+                Vertex::new([corner.x as f32, corner.y as f32, z])
+                    .with_color(self.current_color)
+                    .with_tex_coord(tex_coord)
+            );
+        }
+
+        mesh.indices.push(index_start + 0);
+        mesh.indices.push(index_start + 1);
+        mesh.indices.push(index_start + 2);
+        mesh.indices.push(index_start + 2);
+        mesh.indices.push(index_start + 3);
+        mesh.indices.push(index_start + 0);
+
+        self.transform_vertices(index_start);
+    }
+
+    fn draw_oriented_rectangle(&mut self, rect: &OrientedRectangle, line_width: FloatType) {
+        let corners = rect.corners();
+
+        for i in 0..4 {
+            self.draw_line(&Line::new(corners[i], corners[(i + 1) % 4]), line_width);
+        }
+    }
+
     fn fill_triangle(&mut self, triangle: &Triangle) {
         let z = self.advance_layer();
 
@@ -331,7 +410,7 @@ impl RenderShape for RenderBatch {
             let point = triangle.points[i];
 
             // Calculate texture coordinates
-            let tex_coord = (point - extent.min) / (extent.max - extent.min);
+            let tex_coord = get_tex_coord(point, &extent);
 
             mesh.vertices.push(
                 Vertex::new([point.x as f32, point.y as f32, z])
@@ -340,5 +419,57 @@ impl RenderShape for RenderBatch {
             );
             mesh.indices.push(index_start + i as u32);
         }
+
+        self.transform_vertices(index_start);
     }
+
+
+    fn fill_circle(&mut self, circle: &Circle) {
+        self.draw_circle(circle.center, circle.radius);
+    }
+
+
+    fn fill_polygon(&mut self, polygon: &Polygon) {
+        let z = self.advance_layer();
+
+        // Get current mesh
+        let mesh = &mut self.meshes[self.current_mesh];
+        let index_start: u32 = mesh.vertices.len() as u32;
+
+        use shape::Bounded;
+        let extent = polygon.bounding_box();
+
+        for point in polygon.points.iter() {
+            // Calculate texture coordinates
+            let tex_coord = get_tex_coord(*point, &extent);
+
+            mesh.vertices.push(
+                Vertex::new([point.x as f32, point.y as f32, z])
+                    .with_color(self.current_color)
+                    .with_tex_coord([tex_coord.x as f32, 1.0 - tex_coord.y as f32])
+            );
+        }
+
+        // Fan out from the first corner
+        for i in 2..polygon.points.len() as u32 {
+            mesh.indices.push(index_start);
+            mesh.indices.push(index_start + i - 1);
+            mesh.indices.push(index_start + i);
+        }
+
+        self.transform_vertices(index_start);
+    }
+}
+
+
+/// Return where a point lies within a rectangle, in the range [0, 1] along each axis.
+/// Points in a rectangle without width or height are at 0 along that axis.
+fn get_tex_coord(point: Vector2, extent: &Rectangle) -> Vector2 {
+    let offset = point - extent.min;
+    let size = extent.max - extent.min;
+
+    Vector2::new(
+        if size.x > 0.0 { offset.x / size.x } else { 0.0 },
+        if size.y > 0.0 { offset.y / size.y } else { 0.0 },
+    )
 }
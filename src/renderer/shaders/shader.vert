@@ -4,8 +4,7 @@ in vec3 position;
 in vec4 color;
 in vec2 texCoord;
 
-uniform vec2 translation = vec2(0, 0);
-uniform vec2 scale = vec2(1, 1);
+uniform mat3 transform = mat3(1);
 uniform uint layers = uint(1);
 
 out FragData {
@@ -15,7 +14,7 @@ out FragData {
 } frag;
 
 void main() {
-    vec2 newPosition = (position.xy + translation) * scale;
+    vec2 newPosition = (transform * vec3(position.xy, 1)).xy;
     float z = 0.99 - 0.98 * position.z / float(layers);
 
     gl_Position = vec4(newPosition, z, 1);
@@ -2,10 +2,15 @@ use gl;
 
 use ::images::{Image, ImageFormat};
 
+use std::cell::{Cell, RefCell};
+
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Texture {
-    handle: u32
+    handle: u32,
+
+    // Whether the texture is stored in main memory instead of on the GPU
+    software: bool,
 }
 
 
@@ -21,9 +26,76 @@ pub enum TextureFilter {
     Linear = gl::LINEAR as isize
 }
 
+
+/// A texture stored in main memory instead of on the GPU
+#[derive(Debug, Clone)]
+pub struct SoftwareTexture {
+    pub width: u32,
+    pub height: u32,
+
+    // Pixels in RGBA format, row by row starting from the top
+    pub pixels: Vec<u8>,
+
+    pub filter: TextureFilter,
+}
+
+
+/// Keeps the textures created on this thread in main memory for as long as it lives.
+/// Used when running without a window, where there is no OpenGL context.
+pub struct SoftwareTextureScope {
+    // Whether textures were already stored in main memory when the scope started
+    was_enabled: bool,
+}
+
+
+thread_local! {
+    // All textures created on this thread in main memory.
+    // The handle of a texture is its index plus one, the first one being the default texture.
+    static SOFTWARE_TEXTURES: RefCell<Vec<SoftwareTexture>> = RefCell::new(vec![SoftwareTexture {
+        width: 1,
+        height: 1,
+        pixels: vec![255; 4],
+        filter: TextureFilter::Linear,
+    }]);
+
+    // Whether textures created on this thread are stored in main memory
+    static SOFTWARE_ENABLED: Cell<bool> = Cell::new(false);
+}
+
+
+impl SoftwareTextureScope {
+    /// Store the textures created on this thread in main memory until the scope is dropped
+    pub fn new() -> SoftwareTextureScope {
+        let was_enabled = SOFTWARE_ENABLED.with(|enabled| enabled.replace(true));
+
+        SoftwareTextureScope {
+            was_enabled
+        }
+    }
+}
+
+
+impl Drop for SoftwareTextureScope {
+    fn drop(&mut self) {
+        let was_enabled = self.was_enabled;
+        SOFTWARE_ENABLED.with(|enabled| enabled.set(was_enabled));
+    }
+}
+
+
+/// Returns true if textures created on this thread are stored in main memory
+pub fn software_textures_enabled() -> bool {
+    SOFTWARE_ENABLED.with(|enabled| enabled.get())
+}
+
+
 impl Texture {
     /// Create new
     pub fn new(width: u32, height: u32, pixels: TextureData) -> Texture {
+        if software_textures_enabled() {
+            return Texture::new_software(width, height, pixels);
+        }
+
         let mut handle = 0;
         unsafe {
             gl::GenTextures(1, &mut handle);
@@ -49,20 +121,60 @@ impl Texture {
         }
 
         Texture {
-            handle
+            handle,
+            software: false,
+        }
+    }
+
+    // Create a texture in main memory
+    fn new_software(width: u32, height: u32, pixels: TextureData) -> Texture {
+        let pixels = match pixels {
+            TextureData::RGBA(slice) => slice.to_vec()
+        };
+
+        SOFTWARE_TEXTURES.with(|textures| {
+            let mut textures = textures.borrow_mut();
+
+            textures.push(SoftwareTexture {
+                width,
+                height,
+                pixels,
+                filter: TextureFilter::Linear,
+            });
+
+            Texture {
+                handle: textures.len() as u32,
+                software: true,
+            }
+        })
+    }
+
+
+    /// Returns a copy of this texture if it is stored in main memory
+    pub fn get_software_texture(&self) -> Option<SoftwareTexture> {
+        if !self.software || self.handle == 0 {
+            return None;
         }
+
+        SOFTWARE_TEXTURES.with(|textures| textures.borrow().get(self.handle as usize - 1).cloned())
     }
 
+
     /// Create an empty uninitialized texture
     pub fn empty() -> Texture {
         Texture {
-            handle: 0
+            handle: 0,
+            software: false,
         }
     }
 
 
     /// Bind this texture
     pub fn bind(&self) {
+        if self.software {
+            return;
+        }
+
         unsafe {
             gl::BindTexture(gl::TEXTURE_2D, self.handle);
         }
@@ -71,6 +183,12 @@ impl Texture {
 
     /// Set the min and mag filter for this texture
     pub fn set_min_mag_filter(&mut self, min_filter: TextureFilter, mag_filter: TextureFilter) {
+        if self.software {
+            // Textures in main memory are sampled with a single filter
+            self.set_software_filter(mag_filter);
+            return;
+        }
+
         self.bind();
         unsafe {
             gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, min_filter as i32);
@@ -80,17 +198,41 @@ impl Texture {
 
     /// Set the min and mag filter for this texture
     pub fn set_filter(&mut self, filter: TextureFilter) {
+        if self.software {
+            self.set_software_filter(filter);
+            return;
+        }
+
         self.bind();
         unsafe {
             gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MIN_FILTER, filter as i32);
             gl::TexParameteri(gl::TEXTURE_2D, gl::TEXTURE_MAG_FILTER, filter as i32);
         }
     }
+
+
+    // Set the filter of a texture stored in main memory
+    fn set_software_filter(&mut self, filter: TextureFilter) {
+        if self.handle == 0 {
+            return;
+        }
+
+        SOFTWARE_TEXTURES.with(|textures| {
+            if let Some(texture) = textures.borrow_mut().get_mut(self.handle as usize - 1) {
+                texture.filter = filter;
+            }
+        });
+    }
 }
 
 
 impl Default for Texture {
     fn default() -> Self {
+        // The first texture in main memory is the default one
+        if software_textures_enabled() {
+            return Texture { handle: 1, software: true };
+        }
+
         static mut DEFAULT_TEXTURE: Option<Texture> = None;
         unsafe {
             if let Some(texture) = DEFAULT_TEXTURE {
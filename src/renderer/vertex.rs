@@ -1,6 +1,6 @@
 
 
-#[derive(Debug)]
+#[derive(Debug, Copy, Clone)]
 #[repr(C)]
 pub struct Vertex {
     pub position: [f32; 3],
@@ -1,14 +1,114 @@
-use ::{Vector2};
+use ::shape::Rectangle;
+use ::{Vector2, Transform, FloatType};
 
+/// Views implement either 'get_transform' or 'get_transformation', each is built from the other by default
 pub trait View {
-    /// Return the translation and scaling respectively
-    fn get_transformation(&self) -> (Vector2, Vector2);
+    /// Return the translation and scaling respectively.
+    /// Rotations and other transforms can't be described this way, they are left out.
+    #[deprecated(note = "implement 'get_transform' instead")]
+    fn get_transformation(&self) -> (Vector2, Vector2) {
+        let transform = self.get_transform();
+        let scale = Vector2::new(transform.x_axis.x, transform.y_axis.y);
 
+        (transform.translation / scale, scale)
+    }
 
-    /// Maps a point in the range [-1, 1] to the view's bounds
-    fn ndc_to_world(&self, point: Vector2) -> Vector2 {
+
+    /// Return the transform from world coordinates to the range [-1, 1]
+    fn get_transform(&self) -> Transform {
+        #[allow(deprecated)]
         let (translation, scale) = self.get_transformation();
 
-        point / scale - translation
+        Transform::scale(scale) * Transform::translate(translation)
+    }
+
+
+    /// Maps a point in the range [-1, 1] to the view's bounds.
+    /// Views that collapse the world onto a line or point can't be undone, the point is returned unchanged.
+    fn ndc_to_world(&self, point: Vector2) -> Vector2 {
+        match self.get_transform().inverse() {
+            Some(inverse) => inverse.transform_point(point),
+            None => point
+        }
     }
+
+
+    /// Maps a point within the view's bounds to the range [-1, 1]
+    fn world_to_ndc(&self, point: Vector2) -> Vector2 {
+        self.get_transform().transform_point(point)
+    }
+
+
+    /// Return the smallest rectangle that contains everything that is visible
+    fn get_bounds(&self) -> Rectangle {
+        let corners = [
+            Vector2::new(-1.0, -1.0),
+            Vector2::new(1.0, -1.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(-1.0, 1.0),
+        ];
+
+        // Rotated views see the corners of the world from any direction
+        let first = self.ndc_to_world(corners[0]);
+        let mut bounds = Rectangle { min: first, max: first };
+
+        for &corner in corners[1..].iter() {
+            let point = self.ndc_to_world(corner);
+
+            bounds.min = Vector2::new(min!(bounds.min.x, point.x), min!(bounds.min.y, point.y));
+            bounds.max = Vector2::new(max!(bounds.max.x, point.x), max!(bounds.max.y, point.y));
+        }
+
+        bounds
+    }
+
+
+    /// Maps a point in window coordinates to the view's bounds
+    ///
+    /// # Arguments
+    ///
+    /// * 'point' - The point in pixels, from the top left corner of the window
+    /// * 'window_size' - The width and height of the window in pixels
+    fn window_to_world(&self, point: Vector2, window_size: [u32; 2]) -> Vector2 {
+        self.ndc_to_world(window_to_ndc(point, window_size))
+    }
+
+
+    /// Maps a point within the view's bounds to window coordinates
+    ///
+    /// # Arguments
+    ///
+    /// * 'point' - The point in the world
+    /// * 'window_size' - The width and height of the window in pixels
+    fn world_to_window(&self, point: Vector2, window_size: [u32; 2]) -> Vector2 {
+        ndc_to_window(self.world_to_ndc(point), window_size)
+    }
+}
+
+
+/// Map window coordinates into the range [-1, 1] (right and top are positive)
+///
+/// # Arguments
+///
+/// * 'point' - The point in pixels, from the top left corner of the window
+/// * 'window_size' - The width and height of the window in pixels
+pub fn window_to_ndc(point: Vector2, window_size: [u32; 2]) -> Vector2 {
+    Vector2::new(
+        point.x / window_size[0] as FloatType * 2.0 - 1.0,
+        1.0 - point.y / window_size[1] as FloatType * 2.0,
+    )
+}
+
+
+/// Map a point in the range [-1, 1] to window coordinates, in pixels from the top left corner
+///
+/// # Arguments
+///
+/// * 'point' - The point in the range [-1, 1] (right and top are positive)
+/// * 'window_size' - The width and height of the window in pixels
+pub fn ndc_to_window(point: Vector2, window_size: [u32; 2]) -> Vector2 {
+    Vector2::new(
+        (point.x + 1.0) / 2.0 * window_size[0] as FloatType,
+        (1.0 - point.y) / 2.0 * window_size[1] as FloatType,
+    )
 }
@@ -0,0 +1,313 @@
+use ::collision::{Collide, Overlap, RayCast, RayHit, Intersection, Sweep, Impact, PointQuery, Distance};
+use super::{Line, Rectangle, Bounded, Translate};
+use ::{Vector2, FloatType};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Circle {
+    pub center: Vector2,
+    pub radius: FloatType,
+}
+
+
+impl Circle {
+    /// Create a new circle
+    pub fn new(center: Vector2, radius: FloatType) -> Circle {
+        Circle {
+            center,
+            radius: radius.abs(),
+        }
+    }
+
+
+    /// Return true if circle contains the point
+    pub fn contains(&self, point: Vector2) -> bool {
+        (point - self.center).length() <= self.radius
+    }
+
+
+    /// Translate circle in a direction
+    pub fn translate(&mut self, delta: Vector2) {
+        self.center += delta;
+    }
+
+
+    /// Return the point within a rectangle that is closest to the circle's center
+    fn nearest_point_within(&self, rect: &Rectangle) -> Vector2 {
+        Vector2::new(
+            max!(rect.min.x, min!(self.center.x, rect.max.x)),
+            max!(rect.min.y, min!(self.center.y, rect.max.y)),
+        )
+    }
+}
+
+
+impl Bounded for Circle {
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::centered(self.center, Vector2::new(2.0 * self.radius, 2.0 * self.radius))
+    }
+}
+
+
+impl Translate for Circle {
+    fn translate(&mut self, delta: Vector2) {
+        Circle::translate(self, delta);
+    }
+}
+
+
+
+impl PointQuery for Circle {
+    fn contains_point(&self, point: Vector2) -> bool {
+        self.contains(point)
+    }
+
+    fn closest_point(&self, point: Vector2) -> Vector2 {
+        // Points at the center are closest to the top
+        let delta = point - self.center;
+        let direction = if delta.length() > 0.0 { delta.normal() } else { Vector2::new(0.0, 1.0) };
+
+        self.center + self.radius * direction
+    }
+}
+
+
+impl Distance<Circle> for Circle {
+    fn distance(&self, other: &Circle) -> FloatType {
+        (self.center - other.center).length() - self.radius - other.radius
+    }
+}
+
+
+impl Distance<Rectangle> for Circle {
+    fn distance(&self, other: &Rectangle) -> FloatType {
+        other.signed_distance(self.center) - self.radius
+    }
+}
+
+
+impl Distance<Circle> for Rectangle {
+    fn distance(&self, other: &Circle) -> FloatType {
+        other.distance(self)
+    }
+}
+
+
+impl Collide<Circle> for Circle {
+    fn intersects(&self, other: &Circle) -> bool {
+        (self.center - other.center).length() < self.radius + other.radius
+    }
+
+    fn overlap(&self, other: &Circle) -> Option<Overlap> {
+        let delta = self.center - other.center;
+        let distance = delta.length();
+        let depth = self.radius + other.radius - distance;
+
+        if depth > 0.0 {
+            // Circles with the same center are pushed apart upwards
+            let normal = if distance > 0.0 { delta / distance } else { Vector2::new(0.0, 1.0) };
+
+            Some(Overlap {
+                depth,
+                resolve: depth * normal,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+
+impl Collide<Rectangle> for Circle {
+    fn intersects(&self, other: &Rectangle) -> bool {
+        (self.center - self.nearest_point_within(other)).length() < self.radius
+    }
+
+    fn overlap(&self, other: &Rectangle) -> Option<Overlap> {
+        if other.contains(self.center) {
+            // Move out through the nearest side of the rectangle
+            let left = self.center.x - other.min.x;
+            let right = other.max.x - self.center.x;
+            let bottom = self.center.y - other.min.y;
+            let top = other.max.y - self.center.y;
+
+            let nearest = min!(min!(left, right), min!(bottom, top));
+            let depth = nearest + self.radius;
+
+            let resolve = if nearest == left {
+                Vector2::new(-depth, 0.0)
+            } else if nearest == right {
+                Vector2::new(depth, 0.0)
+            } else if nearest == bottom {
+                Vector2::new(0.0, -depth)
+            } else {
+                Vector2::new(0.0, depth)
+            };
+
+            Some(Overlap {
+                depth,
+                resolve,
+            })
+        } else {
+            let delta = self.center - self.nearest_point_within(other);
+            let distance = delta.length();
+            let depth = self.radius - distance;
+
+            if depth > 0.0 {
+                Some(Overlap {
+                    depth,
+                    resolve: depth * delta / distance,
+                })
+            } else {
+                None
+            }
+        }
+    }
+}
+
+
+impl Collide<Circle> for Rectangle {
+    fn intersects(&self, other: &Circle) -> bool {
+        other.intersects(self)
+    }
+
+    fn overlap(&self, other: &Circle) -> Option<Overlap> {
+        match other.overlap(self) {
+            Some(overlap) => Some(Overlap {
+                depth: overlap.depth,
+                resolve: -overlap.resolve,
+            }),
+            None => None
+        }
+    }
+}
+
+
+impl RayCast for Circle {
+    fn ray_intersection(&self, origin: Vector2, direction: Vector2) -> Option<Intersection> {
+        match self.ray_hit(origin, direction) {
+            Some(hit) => Some(hit.entry),
+            None => None
+        }
+    }
+
+    fn ray_hit(&self, origin: Vector2, direction: Vector2) -> Option<RayHit> {
+        // Solve |origin + t * direction - center| = radius for t
+        let delta = origin - self.center;
+
+        let a = direction.dot(direction);
+        let b = 2.0 * delta.dot(direction);
+        let c = delta.dot(delta) - self.radius * self.radius;
+
+        let discriminant = b * b - 4.0 * a * c;
+
+        // Missed if the ray never gets close enough to the center
+        if a == 0.0 || discriminant < 0.0 {
+            return None;
+        }
+
+        // The two times the ray crosses the circle
+        let entry_time = (-b - discriminant.sqrt()) / (2.0 * a);
+        let exit_time = (-b + discriminant.sqrt()) / (2.0 * a);
+
+        let entry = origin + entry_time * direction;
+        let exit = origin + exit_time * direction;
+
+        Some(RayHit {
+            entry: Intersection {
+                time: entry_time,
+                point: entry,
+                normal: (entry - self.center).normal(),
+            },
+
+            exit: Intersection {
+                time: exit_time,
+                point: exit,
+                normal: (exit - self.center).normal(),
+            },
+        })
+    }
+}
+
+
+impl Sweep<Circle> for Circle {
+    fn sweep(&self, path: Vector2, other: &Circle) -> Option<Impact> {
+        // Combine the sizes of the circles: Minkowski addition with the origin in 'other'
+        let sum = Circle::new(other.center, self.radius + other.radius);
+
+        let line = Line {
+            start: self.center,
+            end: self.center + path,
+        };
+
+        match sum.line_intersection(&line) {
+            Some(intersection) => Some(Impact::from(intersection)),
+            None => None
+        }
+    }
+}
+
+
+impl Sweep<Rectangle> for Circle {
+    fn sweep(&self, path: Vector2, other: &Rectangle) -> Option<Impact> {
+        let line = Line {
+            start: self.center,
+            end: self.center + path,
+        };
+
+        // The Minkowski sum of a rectangle and a circle is a rectangle with rounded corners,
+        // which is the union of two expanded rectangles and a circle at every corner
+        let horizontal = Rectangle {
+            min: other.min - Vector2::new(self.radius, 0.0),
+            max: other.max + Vector2::new(self.radius, 0.0),
+        };
+
+        let vertical = Rectangle {
+            min: other.min - Vector2::new(0.0, self.radius),
+            max: other.max + Vector2::new(0.0, self.radius),
+        };
+
+        let corners = [
+            Circle::new(Vector2::new(other.min.x, other.min.y), self.radius),
+            Circle::new(Vector2::new(other.min.x, other.max.y), self.radius),
+            Circle::new(Vector2::new(other.max.x, other.min.y), self.radius),
+            Circle::new(Vector2::new(other.max.x, other.max.y), self.radius),
+        ];
+
+        // The first part of the sum that is hit
+        let mut first: Option<Intersection> = None;
+
+        let intersections = corners.iter()
+            .map(|corner| corner.line_intersection(&line))
+            .chain(Some(horizontal.line_intersection(&line)))
+            .chain(Some(vertical.line_intersection(&line)));
+
+        for intersection in intersections {
+            if let Some(intersection) = intersection {
+                let is_first = match first {
+                    Some(ref first) => intersection.time < first.time,
+                    None => true,
+                };
+
+                if is_first {
+                    first = Some(intersection);
+                }
+            }
+        }
+
+        match first {
+            Some(intersection) => Some(Impact::from(intersection)),
+            None => None
+        }
+    }
+}
+
+
+impl Sweep<Circle> for Rectangle {
+    fn sweep(&self, path: Vector2, other: &Circle) -> Option<Impact> {
+        // Moving the rectangle towards the circle is the same as moving the circle towards the rectangle
+        match other.sweep(-path, self) {
+            Some(impact) => Some(impact.inverse()),
+            None => None
+        }
+    }
+}
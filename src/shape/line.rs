@@ -1,8 +1,9 @@
 
-use super::Rectangle;
+use ::collision::{Collide, Overlap, RayCast, Intersection, PointQuery, Distance};
+use super::{Rectangle, Polygon, Translate};
 use ::{Vector2, FloatType};
 
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 pub struct Line {
     pub start: Vector2,
     pub end: Vector2
@@ -42,4 +43,122 @@ impl Line {
             (self.start - self.end).abs()    // The extent of the line
         )
     }
+
+
+    /// Return the line as a polygon without any area
+    fn as_polygon(&self) -> Polygon {
+        Polygon::from(*self)
+    }
+}
+
+
+impl Translate for Line {
+    fn translate(&mut self, delta: Vector2) {
+        self.start += delta;
+        self.end += delta;
+    }
+}
+
+
+/// How far a point can be from a line while still being on it
+const POINT_TOLERANCE: FloatType = 1e-9;
+
+
+impl PointQuery for Line {
+    fn contains_point(&self, point: Vector2) -> bool {
+        // Lines have no area, allow for rounding errors
+        (self.closest_point(point) - point).length() <= POINT_TOLERANCE
+    }
+
+    fn closest_point(&self, point: Vector2) -> Vector2 {
+        let delta = self.get_delta();
+
+        let length_squared = delta.dot(delta);
+        if length_squared == 0.0 {
+            return self.start;
+        }
+
+        // How far along the line the point is, limited to the end points
+        let along = (point - self.start).dot(delta) / length_squared;
+        self.start + max!(0.0, min!(along, 1.0)) * delta
+    }
+}
+
+
+impl Distance<Line> for Line {
+    fn distance(&self, other: &Line) -> FloatType {
+        // Lines have no inside, so crossing lines are not separated but do not overlap either
+        max!(self.as_polygon().distance(&other.as_polygon()), 0.0)
+    }
+}
+
+
+impl Distance<Rectangle> for Line {
+    fn distance(&self, other: &Rectangle) -> FloatType {
+        self.as_polygon().distance(&Polygon::from(*other))
+    }
+}
+
+
+impl Distance<Line> for Rectangle {
+    fn distance(&self, other: &Line) -> FloatType {
+        other.distance(self)
+    }
+}
+
+
+impl RayCast for Line {
+    fn ray_intersection(&self, origin: Vector2, direction: Vector2) -> Option<Intersection> {
+        let delta = self.get_delta();
+
+        // Parallel lines never cross
+        let denominator = direction.cross(delta);
+        if denominator == 0.0 {
+            return None;
+        }
+
+        // Solve 'origin + time * direction = start + along * delta'
+        let offset = self.start - origin;
+        let time = offset.cross(delta) / denominator;
+        let along = offset.cross(direction) / denominator;
+
+        // Missed if the ray crosses outside the line's endpoints
+        if along < 0.0 || along > 1.0 {
+            return None;
+        }
+
+        // The side of the line facing the ray
+        let mut normal = delta.perpendicular().normal();
+        if normal.dot(direction) > 0.0 {
+            normal = -normal;
+        }
+
+        Some(Intersection {
+            time,
+            point: origin + time * direction,
+            normal,
+        })
+    }
+}
+
+
+impl Collide<Rectangle> for Line {
+    fn intersects(&self, other: &Rectangle) -> bool {
+        self.as_polygon().intersects(other)
+    }
+
+    fn overlap(&self, other: &Rectangle) -> Option<Overlap> {
+        self.as_polygon().overlap(other)
+    }
+}
+
+
+impl Collide<Line> for Rectangle {
+    fn intersects(&self, other: &Line) -> bool {
+        self.intersects(&other.as_polygon())
+    }
+
+    fn overlap(&self, other: &Line) -> Option<Overlap> {
+        self.overlap(&other.as_polygon())
+    }
 }
@@ -1,5 +1,6 @@
 
 
+use ::Vector2;
 
 mod rectangle;
 pub use self::rectangle::Rectangle;
@@ -10,6 +11,15 @@ pub use self::line::Line;
 mod triangle;
 pub use self::triangle::Triangle;
 
+mod circle;
+pub use self::circle::Circle;
+
+mod polygon;
+pub use self::polygon::Polygon;
+
+mod oriented_rectangle;
+pub use self::oriented_rectangle::OrientedRectangle;
+
 mod render_shape;
 pub use self::render_shape::RenderShape;
 
@@ -20,3 +30,10 @@ pub trait Bounded {
     /// Return the object's bounding box
     fn bounding_box(&self) -> Rectangle;
 }
+
+
+/// For shapes that can be moved around
+pub trait Translate {
+    /// Translate the shape in a direction
+    fn translate(&mut self, delta: Vector2);
+}
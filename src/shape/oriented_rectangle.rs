@@ -0,0 +1,179 @@
+use ::collision::{Collide, Overlap, RayCast, RayHit, Intersection, Sweep, Impact, PointQuery};
+use super::{Rectangle, Polygon, Bounded, Translate};
+use ::{Vector2, FloatType, Transform};
+
+/// A rectangle that may be rotated around its center
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct OrientedRectangle {
+    pub center: Vector2,
+
+    /// Half of the width and height of the rectangle, before rotating it
+    pub half_extents: Vector2,
+
+    /// The counter-clockwise rotation, in radians
+    pub rotation: FloatType,
+}
+
+
+impl OrientedRectangle {
+    /// Create a new oriented rectangle
+    ///
+    /// # Arguments
+    ///
+    /// * 'center' - The center of the rectangle, which it rotates around
+    /// * 'half_extents' - Half of the width and height of the rectangle
+    /// * 'rotation' - The counter-clockwise rotation, in radians
+    pub fn new(center: Vector2, half_extents: Vector2, rotation: FloatType) -> OrientedRectangle {
+        OrientedRectangle {
+            center,
+            half_extents: half_extents.abs(),
+            rotation,
+        }
+    }
+
+
+    /// Return the corners of the rectangle, in counter-clockwise order starting at the rotated bottom left corner
+    pub fn corners(&self) -> [Vector2; 4] {
+        let x = Vector2::new(self.half_extents.x, 0.0).rotate(self.rotation);
+        let y = Vector2::new(0.0, self.half_extents.y).rotate(self.rotation);
+
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+
+    /// Return true if rectangle contains the point
+    pub fn contains(&self, point: Vector2) -> bool {
+        // Rotate the point into the space of the rectangle
+        let local = (point - self.center).rotate(-self.rotation);
+
+        local.x.abs() <= self.half_extents.x && local.y.abs() <= self.half_extents.y
+    }
+
+
+    /// Return the rectangle rotated further around its center
+    pub fn rotated(mut self, angle: FloatType) -> OrientedRectangle {
+        self.rotation += angle;
+        self
+    }
+
+
+    /// Return the rectangle with a transform applied to its corners.
+    /// Scaling a rotated rectangle can skew it, so the result is a polygon.
+    pub fn transformed(&self, transform: &Transform) -> Polygon {
+        Polygon::from(*self).transformed(transform)
+    }
+}
+
+
+impl From<Rectangle> for OrientedRectangle {
+    fn from(rect: Rectangle) -> Self {
+        rect.rotated(0.0)
+    }
+}
+
+
+impl From<OrientedRectangle> for Polygon {
+    fn from(rect: OrientedRectangle) -> Self {
+        // Half extents set directly could be negative, which would turn the corners clockwise
+        Polygon::new(rect.corners().to_vec())
+    }
+}
+
+
+impl Bounded for OrientedRectangle {
+    fn bounding_box(&self) -> Rectangle {
+        Polygon::from(*self).bounding_box()
+    }
+}
+
+
+impl Translate for OrientedRectangle {
+    fn translate(&mut self, delta: Vector2) {
+        self.center += delta;
+    }
+}
+
+
+impl PointQuery for OrientedRectangle {
+    fn contains_point(&self, point: Vector2) -> bool {
+        self.contains(point)
+    }
+
+    fn closest_point(&self, point: Vector2) -> Vector2 {
+        Polygon::from(*self).closest_point(point)
+    }
+}
+
+
+
+impl Collide<OrientedRectangle> for OrientedRectangle {
+    fn intersects(&self, other: &OrientedRectangle) -> bool {
+        Polygon::from(*self).intersects(&Polygon::from(*other))
+    }
+
+    fn overlap(&self, other: &OrientedRectangle) -> Option<Overlap> {
+        Polygon::from(*self).overlap(&Polygon::from(*other))
+    }
+}
+
+
+impl Collide<Rectangle> for OrientedRectangle {
+    fn intersects(&self, other: &Rectangle) -> bool {
+        Polygon::from(*self).intersects(other)
+    }
+
+    fn overlap(&self, other: &Rectangle) -> Option<Overlap> {
+        Polygon::from(*self).overlap(other)
+    }
+}
+
+
+impl Collide<OrientedRectangle> for Rectangle {
+    fn intersects(&self, other: &OrientedRectangle) -> bool {
+        self.intersects(&Polygon::from(*other))
+    }
+
+    fn overlap(&self, other: &OrientedRectangle) -> Option<Overlap> {
+        self.overlap(&Polygon::from(*other))
+    }
+}
+
+
+impl RayCast for OrientedRectangle {
+    fn ray_intersection(&self, origin: Vector2, direction: Vector2) -> Option<Intersection> {
+        match self.ray_hit(origin, direction) {
+            Some(hit) => Some(hit.entry),
+            None => None
+        }
+    }
+
+    fn ray_hit(&self, origin: Vector2, direction: Vector2) -> Option<RayHit> {
+        Polygon::from(*self).ray_hit(origin, direction)
+    }
+}
+
+
+impl Sweep<OrientedRectangle> for OrientedRectangle {
+    fn sweep(&self, path: Vector2, other: &OrientedRectangle) -> Option<Impact> {
+        Polygon::from(*self).sweep(path, &Polygon::from(*other))
+    }
+}
+
+
+impl Sweep<Rectangle> for OrientedRectangle {
+    fn sweep(&self, path: Vector2, other: &Rectangle) -> Option<Impact> {
+        Polygon::from(*self).sweep(path, other)
+    }
+}
+
+
+impl Sweep<OrientedRectangle> for Rectangle {
+    fn sweep(&self, path: Vector2, other: &OrientedRectangle) -> Option<Impact> {
+        self.sweep(path, &Polygon::from(*other))
+    }
+}
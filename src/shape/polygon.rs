@@ -0,0 +1,439 @@
+use ::collision::{Collide, Overlap, RayCast, RayHit, Intersection, Sweep, Impact, PointQuery, Distance};
+use ::collision::{ranges_intersect, range_overlap};
+use super::{Line, Rectangle, Triangle, Bounded, Translate};
+use ::{Vector2, FloatType, Transform};
+
+use num_traits::Float;
+
+use std::cmp::Ordering;
+
+/// A convex polygon
+#[derive(Clone, PartialEq, Debug)]
+pub struct Polygon {
+    /// The corners of the polygon, in counter-clockwise order
+    pub points: Vec<Vector2>,
+}
+
+
+impl Polygon {
+    /// Create a new polygon from the corners of a convex polygon, in any winding order
+    pub fn new(mut points: Vec<Vector2>) -> Polygon {
+        // Make sure the points are counter-clockwise
+        let mut area = 0.0;
+        for i in 0..points.len() {
+            area += points[i].cross(points[(i + 1) % points.len()]);
+        }
+
+        if area < 0.0 {
+            points.reverse();
+        }
+
+        Polygon { points }
+    }
+
+
+    /// Create the smallest convex polygon that contains all points
+    pub fn convex_hull(points: &[Vector2]) -> Polygon {
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| {
+            match a.x.partial_cmp(&b.x) {
+                Some(Ordering::Equal) | None => a.y.partial_cmp(&b.y).unwrap_or(Ordering::Equal),
+                Some(ordering) => ordering
+            }
+        });
+        sorted.dedup();
+
+        if sorted.len() < 3 {
+            return Polygon { points: sorted };
+        }
+
+        // Build the lower and upper halves of the hull, only turning left
+        let mut lower: Vec<Vector2> = Vec::new();
+        for &point in sorted.iter() {
+            while lower.len() >= 2 && turns_right(lower[lower.len() - 2], lower[lower.len() - 1], point) {
+                lower.pop();
+            }
+            lower.push(point);
+        }
+
+        let mut upper: Vec<Vector2> = Vec::new();
+        for &point in sorted.iter().rev() {
+            while upper.len() >= 2 && turns_right(upper[upper.len() - 2], upper[upper.len() - 1], point) {
+                upper.pop();
+            }
+            upper.push(point);
+        }
+
+        // The last point of each half is the first point of the other
+        lower.pop();
+        upper.pop();
+        lower.extend(upper);
+
+        Polygon { points: lower }
+    }
+
+
+    /// Return true if polygon contains the point
+    pub fn contains(&self, point: Vector2) -> bool {
+        self.edges().into_iter().all(|(start, end)| (end - start).cross(point - start) >= 0.0)
+    }
+
+
+    /// Calculate the center of the polygon, the average of its corners
+    pub fn center(&self) -> Vector2 {
+        let mut sum = Vector2::zero();
+        for &point in self.points.iter() {
+            sum += point;
+        }
+
+        sum / self.points.len() as FloatType
+    }
+
+
+    /// Translate polygon in a direction
+    pub fn translate(&mut self, delta: Vector2) {
+        for point in self.points.iter_mut() {
+            *point += delta;
+        }
+    }
+
+
+    /// Return the polygon with a transform applied to its corners
+    pub fn transformed(&self, transform: &Transform) -> Polygon {
+        // Mirroring turns the corners clockwise
+        Polygon::new(self.points.iter().map(|&point| transform.transform_point(point)).collect())
+    }
+
+
+    /// Return the edges of the polygon, as pairs of start and end points
+    fn edges(&self) -> Vec<(Vector2, Vector2)> {
+        let count = self.points.len();
+        (0..count).map(|i| (self.points[i], self.points[(i + 1) % count])).collect()
+    }
+
+
+    /// Return the outward facing normals of all edges
+    fn normals(&self) -> Vec<Vector2> {
+        self.edges().into_iter()
+            .filter(|&(start, end)| start != end)
+            .map(|(start, end)| -(end - start).perpendicular().normal())
+            .collect()
+    }
+
+
+    /// Project the polygon onto an axis
+    /// Format: (min, max)
+    fn project(&self, axis: Vector2) -> (FloatType, FloatType) {
+        let mut min = FloatType::infinity();
+        let mut max = FloatType::neg_infinity();
+
+        for point in self.points.iter() {
+            let distance = point.dot(axis);
+
+            min = min!(min, distance);
+            max = max!(max, distance);
+        }
+
+        (min, max)
+    }
+}
+
+
+/// Returns true if the path a -> b -> c turns clockwise, or not at all
+fn turns_right(a: Vector2, b: Vector2, c: Vector2) -> bool {
+    (b - a).cross(c - a) <= 0.0
+}
+
+
+impl From<Triangle> for Polygon {
+    fn from(triangle: Triangle) -> Self {
+        Polygon::new(triangle.points.to_vec())
+    }
+}
+
+
+impl From<Rectangle> for Polygon {
+    fn from(rect: Rectangle) -> Self {
+        Polygon::new(vec![
+            Vector2::new(rect.min.x, rect.min.y),
+            Vector2::new(rect.max.x, rect.min.y),
+            Vector2::new(rect.max.x, rect.max.y),
+            Vector2::new(rect.min.x, rect.max.y),
+        ])
+    }
+}
+
+
+impl From<Line> for Polygon {
+    fn from(line: Line) -> Self {
+        Polygon {
+            points: vec![line.start, line.end]
+        }
+    }
+}
+
+
+impl Bounded for Polygon {
+    fn bounding_box(&self) -> Rectangle {
+        let (min_x, max_x) = self.project(Vector2::new(1.0, 0.0));
+        let (min_y, max_y) = self.project(Vector2::new(0.0, 1.0));
+
+        Rectangle {
+            min: Vector2::new(min_x, min_y),
+            max: Vector2::new(max_x, max_y),
+        }
+    }
+}
+
+
+impl Translate for Polygon {
+    fn translate(&mut self, delta: Vector2) {
+        Polygon::translate(self, delta);
+    }
+}
+
+
+
+impl PointQuery for Polygon {
+    fn contains_point(&self, point: Vector2) -> bool {
+        self.contains(point)
+    }
+
+    fn closest_point(&self, point: Vector2) -> Vector2 {
+        // The closest of the closest points on every edge
+        let mut closest: Option<(FloatType, Vector2)> = None;
+
+        for (start, end) in self.edges().into_iter() {
+            let candidate = Line::new(start, end).closest_point(point);
+            let distance = (point - candidate).length();
+
+            let is_closer = match closest {
+                Some((closest_distance, _)) => distance < closest_distance,
+                None => true,
+            };
+
+            if is_closer {
+                closest = Some((distance, candidate));
+            }
+        }
+
+        match closest {
+            Some((_, closest)) => closest,
+            None => point
+        }
+    }
+}
+
+
+impl Distance<Polygon> for Polygon {
+    fn distance(&self, other: &Polygon) -> FloatType {
+        if self.intersects(other) {
+            match self.overlap(other) {
+                Some(overlap) => -overlap.depth,
+                None => 0.0
+            }
+        } else {
+            // Separated convex polygons are closest at a corner of one of them
+            let mut distance = FloatType::infinity();
+
+            for &point in self.points.iter() {
+                distance = min!(distance, (point - other.closest_point(point)).length());
+            }
+
+            for &point in other.points.iter() {
+                distance = min!(distance, (point - self.closest_point(point)).length());
+            }
+
+            distance
+        }
+    }
+}
+
+
+impl Collide<Polygon> for Polygon {
+    fn intersects(&self, other: &Polygon) -> bool {
+        // Separating axis theorem: the polygons intersect if they overlap on every edge normal
+        self.normals().into_iter().chain(other.normals()).all(|axis| {
+            let (a_min, a_max) = self.project(axis);
+            let (b_min, b_max) = other.project(axis);
+
+            ranges_intersect(a_min, a_max, b_min, b_max)
+        })
+    }
+
+    fn overlap(&self, other: &Polygon) -> Option<Overlap> {
+        // The axis along which the polygons overlap the least
+        let mut smallest: Option<(FloatType, Vector2)> = None;
+
+        for axis in self.normals().into_iter().chain(other.normals()) {
+            let (a_min, a_max) = self.project(axis);
+            let (b_min, b_max) = other.project(axis);
+
+            match range_overlap(a_min, a_max, b_min, b_max) {
+                // Found a separating axis
+                None => return None,
+
+                Some(overlap) => {
+                    let is_smallest = match smallest {
+                        Some((smallest, _)) => overlap.abs() < smallest.abs(),
+                        None => true,
+                    };
+
+                    if is_smallest {
+                        smallest = Some((overlap, axis));
+                    }
+                }
+            }
+        }
+
+        match smallest {
+            Some((overlap, axis)) => Some(Overlap {
+                depth: overlap.abs(),
+                resolve: -overlap * axis,
+            }),
+            None => None
+        }
+    }
+}
+
+
+impl Collide<Rectangle> for Polygon {
+    fn intersects(&self, other: &Rectangle) -> bool {
+        self.intersects(&Polygon::from(*other))
+    }
+
+    fn overlap(&self, other: &Rectangle) -> Option<Overlap> {
+        self.overlap(&Polygon::from(*other))
+    }
+}
+
+
+impl Collide<Polygon> for Rectangle {
+    fn intersects(&self, other: &Polygon) -> bool {
+        Polygon::from(*self).intersects(other)
+    }
+
+    fn overlap(&self, other: &Polygon) -> Option<Overlap> {
+        Polygon::from(*self).overlap(other)
+    }
+}
+
+
+impl RayCast for Polygon {
+    fn ray_intersection(&self, origin: Vector2, direction: Vector2) -> Option<Intersection> {
+        match self.ray_hit(origin, direction) {
+            Some(hit) => Some(hit.entry),
+            None => None
+        }
+    }
+
+    fn ray_hit(&self, origin: Vector2, direction: Vector2) -> Option<RayHit> {
+        // Clip the ray against every edge: the ray is inside the polygon between
+        // the time it entered the last edge and the time it left the first edge
+        let mut entry: Option<(FloatType, Vector2)> = None;
+        let mut exit: Option<(FloatType, Vector2)> = None;
+
+        for (start, end) in self.edges().into_iter() {
+            if start == end {
+                continue;
+            }
+
+            let normal = -(end - start).perpendicular().normal();
+
+            // How fast the ray approaches the edge and how far away it is
+            let speed = normal.dot(direction);
+            let distance = normal.dot(start - origin);
+
+            if speed == 0.0 {
+                // Parallel to the edge and outside of it
+                if distance < 0.0 {
+                    return None;
+                }
+            } else {
+                let time = distance / speed;
+
+                if speed < 0.0 {
+                    // Entering the polygon through this edge
+                    let is_later = match entry {
+                        Some((entry_time, _)) => time > entry_time,
+                        None => true,
+                    };
+
+                    if is_later {
+                        entry = Some((time, normal));
+                    }
+                } else {
+                    // Leaving the polygon through this edge
+                    let is_earlier = match exit {
+                        Some((exit_time, _)) => time < exit_time,
+                        None => true,
+                    };
+
+                    if is_earlier {
+                        exit = Some((time, normal));
+                    }
+                }
+            }
+        }
+
+        match (entry, exit) {
+            // Missed if we left the polygon before we entered it
+            (Some((entry_time, entry_normal)), Some((exit_time, exit_normal))) if entry_time <= exit_time => Some(RayHit {
+                entry: Intersection {
+                    time: entry_time,
+                    point: origin + entry_time * direction,
+                    normal: entry_normal,
+                },
+
+                exit: Intersection {
+                    time: exit_time,
+                    point: origin + exit_time * direction,
+                    normal: exit_normal,
+                },
+            }),
+
+            _ => None
+        }
+    }
+}
+
+
+impl Sweep<Polygon> for Polygon {
+    fn sweep(&self, path: Vector2, other: &Polygon) -> Option<Impact> {
+        let center = self.center();
+
+        // Combine the shapes of the polygons: Minkowski addition with the origin in 'other'
+        let mut points = Vec::with_capacity(self.points.len() * other.points.len());
+        for &a in other.points.iter() {
+            for &b in self.points.iter() {
+                points.push(a + center - b);
+            }
+        }
+
+        let sum = Polygon::convex_hull(&points);
+
+        let line = Line {
+            start: center,
+            end: center + path,
+        };
+
+        match sum.line_intersection(&line) {
+            Some(intersection) => Some(Impact::from(intersection)),
+            None => None
+        }
+    }
+}
+
+
+impl Sweep<Rectangle> for Polygon {
+    fn sweep(&self, path: Vector2, other: &Rectangle) -> Option<Impact> {
+        self.sweep(path, &Polygon::from(*other))
+    }
+}
+
+
+impl Sweep<Polygon> for Rectangle {
+    fn sweep(&self, path: Vector2, other: &Polygon) -> Option<Impact> {
+        Polygon::from(*self).sweep(path, other)
+    }
+}
@@ -1,7 +1,7 @@
-use ::collision::{Collide, Overlap, RayCast, Intersection, Sweep, Impact};
+use ::collision::{Collide, Overlap, RayCast, RayHit, Intersection, Sweep, Impact, PointQuery, Distance};
 use ::collision::{in_range, ranges_intersect, range_overlap, sign};
-use super::{Line, Bounded};
-use ::{Vector2};
+use super::{Line, Polygon, OrientedRectangle, Bounded, Translate};
+use ::{Vector2, FloatType, Transform};
 use ::renderer::view::View;
 
 #[derive(Copy, Clone, PartialEq)]
@@ -42,11 +42,38 @@ impl Rectangle {
     }
 
 
+    /// Return the region the rectangle passes through while moving along a path
+    pub fn swept_region(&self, path: Vector2) -> Rectangle {
+        let end = Rectangle {
+            min: self.min + path,
+            max: self.max + path,
+        };
+
+        Rectangle {
+            min: Vector2::new(min!(self.min.x, end.min.x), min!(self.min.y, end.min.y)),
+            max: Vector2::new(max!(self.max.x, end.max.x), max!(self.max.y, end.max.y)),
+        }
+    }
+
+
     /// Translate rectangle by in a direction
     pub fn translate(&mut self, delta: Vector2) {
         self.min += delta;
         self.max += delta;
     }
+
+
+    /// Return the rectangle rotated counter-clockwise around its center by an angle in radians
+    pub fn rotated(&self, angle: FloatType) -> OrientedRectangle {
+        OrientedRectangle::new(self.center(), self.size() / 2.0, angle)
+    }
+
+
+    /// Return the rectangle with a transform applied to its corners.
+    /// Rotating a rectangle turns it away from the axes, so the result is a polygon.
+    pub fn transformed(&self, transform: &Transform) -> Polygon {
+        Polygon::from(*self).transformed(transform)
+    }
 }
 
 
@@ -67,14 +94,73 @@ impl Bounded for Rectangle {
 }
 
 
+impl Translate for Rectangle {
+    fn translate(&mut self, delta: Vector2) {
+        Rectangle::translate(self, delta);
+    }
+}
+
+
 impl View for Rectangle {
-    fn get_transformation(&self) -> (Vector2, Vector2) {
-        (-self.center(), 2.0 / self.size())
+    fn get_transform(&self) -> Transform {
+        Transform::scale(2.0 / self.size()) * Transform::translate(-self.center())
     }
 }
 
 
 
+impl PointQuery for Rectangle {
+    fn contains_point(&self, point: Vector2) -> bool {
+        self.contains(point)
+    }
+
+    fn closest_point(&self, point: Vector2) -> Vector2 {
+        if self.contains(point) {
+            // Move out through the nearest side
+            let left = point.x - self.min.x;
+            let right = self.max.x - point.x;
+            let bottom = point.y - self.min.y;
+            let top = self.max.y - point.y;
+
+            let nearest = min!(min!(left, right), min!(bottom, top));
+
+            if nearest == left {
+                Vector2::new(self.min.x, point.y)
+            } else if nearest == right {
+                Vector2::new(self.max.x, point.y)
+            } else if nearest == bottom {
+                Vector2::new(point.x, self.min.y)
+            } else {
+                Vector2::new(point.x, self.max.y)
+            }
+        } else {
+            Vector2::new(
+                max!(self.min.x, min!(point.x, self.max.x)),
+                max!(self.min.y, min!(point.y, self.max.y)),
+            )
+        }
+    }
+}
+
+
+impl Distance<Rectangle> for Rectangle {
+    fn distance(&self, other: &Rectangle) -> FloatType {
+        // The gaps between the rectangles on each axis, negative if they overlap on that axis
+        let gap = Vector2::new(
+            max!(self.min.x - other.max.x, other.min.x - self.max.x),
+            max!(self.min.y - other.max.y, other.min.y - self.max.y),
+        );
+
+        if gap.x > 0.0 || gap.y > 0.0 {
+            Vector2::new(max!(gap.x, 0.0), max!(gap.y, 0.0)).length()
+        } else {
+            // The shallowest overlap
+            max!(gap.x, gap.y)
+        }
+    }
+}
+
+
 impl Collide<Rectangle> for Rectangle {
     fn intersects(&self, other: &Rectangle) -> bool {
         ranges_intersect(self.min.x, self.max.x, other.min.x, other.max.x) &&
@@ -108,6 +194,13 @@ impl Collide<Rectangle> for Rectangle {
 
 impl RayCast for Rectangle {
     fn ray_intersection(&self, origin: Vector2, direction: Vector2) -> Option<Intersection> {
+        match self.ray_hit(origin, direction) {
+            Some(hit) => Some(hit.entry),
+            None => None
+        }
+    }
+
+    fn ray_hit(&self, origin: Vector2, direction: Vector2) -> Option<RayHit> {
 
 
         /////////////////////////////////////////////////
@@ -151,22 +244,35 @@ impl RayCast for Rectangle {
 
 
         // Calculate the intersection points and normals
-        let mut intersection = Intersection {
+        let mut entry = Intersection {
             time: near_time,
             point: origin + near_time * direction,
             normal: Vector2::zero(),
         };
 
+        let mut exit = Intersection {
+            time: far_time,
+            point: origin + far_time * direction,
+            normal: Vector2::zero(),
+        };
+
         // What side was hit?
         if entry_times.x > entry_times.y {
             // Left/right
-            intersection.normal.x = -sign(direction.x);
+            entry.normal.x = -sign(direction.x);
         } else {
             // Top/bottom
-            intersection.normal.y = -sign(direction.y);
+            entry.normal.y = -sign(direction.y);
+        }
+
+        // What side was left through?
+        if exit_times.x < exit_times.y {
+            exit.normal.x = sign(direction.x);
+        } else {
+            exit.normal.y = sign(direction.y);
         }
 
-        Some(intersection)
+        Some(RayHit { entry, exit })
     }
 }
 
@@ -1,5 +1,5 @@
 
-use super::{Rectangle, Line, Triangle};
+use super::{Rectangle, OrientedRectangle, Line, Triangle, Circle, Polygon};
 use ::{FloatType};
 
 pub trait RenderShape {
@@ -14,6 +14,21 @@ pub trait RenderShape {
     fn draw_rectangle(&mut self, rect: &Rectangle, line_width: FloatType);
 
 
+    /// Render a filled rotated rectangle
+    fn fill_oriented_rectangle(&mut self, rect: &OrientedRectangle);
+
+    /// Render the outline of a rotated rectangle
+    fn draw_oriented_rectangle(&mut self, rect: &OrientedRectangle, line_width: FloatType);
+
+
     /// Render a filled triangle
     fn fill_triangle(&mut self, triangle: &Triangle);
+
+
+    /// Render a filled circle
+    fn fill_circle(&mut self, circle: &Circle);
+
+
+    /// Render a filled convex polygon
+    fn fill_polygon(&mut self, polygon: &Polygon);
 }
@@ -1,7 +1,9 @@
 
-use ::{Vector2};
-use super::{Bounded, Rectangle};
+use ::collision::{Collide, Overlap, RayCast, RayHit, Intersection, PointQuery, Distance};
+use ::{Vector2, FloatType};
+use super::{Bounded, Translate, Rectangle, Polygon, Line};
 
+#[derive(Copy, Clone, Debug)]
 pub struct Triangle {
     pub points: [Vector2; 3]
 }
@@ -31,3 +33,94 @@ impl Bounded for Triangle {
         Rectangle { min, max }
     }
 }
+
+
+impl Translate for Triangle {
+    fn translate(&mut self, delta: Vector2) {
+        for point in self.points.iter_mut() {
+            *point += delta;
+        }
+    }
+}
+
+
+impl PointQuery for Triangle {
+    fn contains_point(&self, point: Vector2) -> bool {
+        Polygon::from(*self).contains(point)
+    }
+
+    fn closest_point(&self, point: Vector2) -> Vector2 {
+        Polygon::from(*self).closest_point(point)
+    }
+}
+
+
+impl Distance<Triangle> for Triangle {
+    fn distance(&self, other: &Triangle) -> FloatType {
+        Polygon::from(*self).distance(&Polygon::from(*other))
+    }
+}
+
+
+impl Distance<Rectangle> for Triangle {
+    fn distance(&self, other: &Rectangle) -> FloatType {
+        Polygon::from(*self).distance(&Polygon::from(*other))
+    }
+}
+
+
+impl Distance<Triangle> for Rectangle {
+    fn distance(&self, other: &Triangle) -> FloatType {
+        other.distance(self)
+    }
+}
+
+
+impl Distance<Line> for Triangle {
+    fn distance(&self, other: &Line) -> FloatType {
+        Polygon::from(*self).distance(&Polygon::from(*other))
+    }
+}
+
+
+impl Distance<Triangle> for Line {
+    fn distance(&self, other: &Triangle) -> FloatType {
+        other.distance(self)
+    }
+}
+
+
+impl RayCast for Triangle {
+    fn ray_intersection(&self, origin: Vector2, direction: Vector2) -> Option<Intersection> {
+        match self.ray_hit(origin, direction) {
+            Some(hit) => Some(hit.entry),
+            None => None
+        }
+    }
+
+    fn ray_hit(&self, origin: Vector2, direction: Vector2) -> Option<RayHit> {
+        Polygon::from(*self).ray_hit(origin, direction)
+    }
+}
+
+
+impl Collide<Rectangle> for Triangle {
+    fn intersects(&self, other: &Rectangle) -> bool {
+        Polygon::from(*self).intersects(other)
+    }
+
+    fn overlap(&self, other: &Rectangle) -> Option<Overlap> {
+        Polygon::from(*self).overlap(other)
+    }
+}
+
+
+impl Collide<Triangle> for Rectangle {
+    fn intersects(&self, other: &Triangle) -> bool {
+        self.intersects(&Polygon::from(*other))
+    }
+
+    fn overlap(&self, other: &Triangle) -> Option<Overlap> {
+        self.overlap(&Polygon::from(*other))
+    }
+}
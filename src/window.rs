@@ -1,5 +1,7 @@
 
 use ::{IntType, Vector2i, FloatType, Vector2};
+use ::FrameStats;
+use ::renderer::view::{self, View};
 
 use glutin;
 use glutin::{GlContext, GlWindow, EventsLoop, ControlFlow, Event, WindowEvent, DeviceEvent};
@@ -14,8 +16,8 @@ use std::path::Path;
 
 /// A window the user can interact with
 pub struct Window {
-    pub(self) gl_window: GlWindow,
-    pub events_loop: EventsLoop,
+    // The OpenGL window and its events, none if the window is headless
+    pub(self) context: Option<WindowContext>,
 
     // Is the window open?
     pub(self) open: bool,
@@ -34,6 +36,18 @@ pub struct Window {
 
     // Position of the cursor
     cursor_position: Vector2i,
+
+    // Statistics about the frames rendered in this window
+    frame_stats: FrameStats,
+
+    // How far the game is between the last fixed update and the next
+    alpha: FloatType,
+}
+
+/// The parts of a window that require a display
+pub(self) struct WindowContext {
+    gl_window: GlWindow,
+    events_loop: EventsLoop,
 }
 
 /// Settings for window creation
@@ -193,21 +207,39 @@ impl Window {
             return Err(format!("{}", e));
         }
 
-        Ok(
-            Window {
-                gl_window,
-                events_loop,
+        let mut window = Window::headless(settings.width, settings.height);
+        window.context = Some(WindowContext {
+            gl_window,
+            events_loop,
+        });
 
-                open: true,
-                size: [settings.width, settings.height],
+        Ok(window)
+    }
 
-                pressed_keys: HashSet::new(),
-                pressed_buttons: HashSet::new(),
-                focused: false,
 
-                cursor_position: [settings.width as IntType / 2, settings.height as IntType / 2].into(),
-            }
-        )
+    /// Creates a window without a display or an OpenGL context.
+    /// It never receives any events.
+    ///
+    /// # Arguments
+    ///
+    /// * 'width' - Width of the window
+    /// * 'height' - Height of the window
+    pub fn headless(width: u32, height: u32) -> Self {
+        Window {
+            context: None,
+
+            open: true,
+            size: [width, height],
+
+            pressed_keys: HashSet::new(),
+            pressed_buttons: HashSet::new(),
+            focused: false,
+
+            cursor_position: [width as IntType / 2, height as IntType / 2].into(),
+
+            frame_stats: FrameStats::default(),
+            alpha: 0.0,
+        }
     }
 
 
@@ -240,9 +272,11 @@ impl Window {
     pub fn poll_events(&mut self) -> Vec<Event> {
         let mut events = Vec::new();
 
-        self.events_loop.poll_events(|event| {
-            events.push(event);
-        });
+        if let Some(ref mut context) = self.context {
+            context.events_loop.poll_events(|event| {
+                events.push(event);
+            });
+        }
 
         let mut filtered_events = Vec::with_capacity(events.len());
 
@@ -351,9 +385,14 @@ impl Window {
     /// If none are available, blocks this thread until one is.
     pub fn wait_events(&mut self) -> Vec<WindowEvent>
     {
+        // A headless window has nothing to wait for
+        if self.is_headless() {
+            return Vec::new();
+        }
+
         let mut window_event = None;
 
-        self.events_loop.run_forever(|e| {
+        self.context.as_mut().unwrap().events_loop.run_forever(|e| {
             match e {
                 glutin::Event::WindowEvent { event, .. } => {
                     window_event = Some(event);
@@ -388,8 +427,10 @@ impl Window {
 
     /// Swap the front and back buffers
     pub fn swap_buffers(&self) -> Result<(), String> {
-        if let Err(e) = self.gl_window.swap_buffers() {
-            return Err(format!("{}", e));
+        if let Some(ref context) = self.context {
+            if let Err(e) = context.gl_window.swap_buffers() {
+                return Err(format!("{}", e));
+            }
         }
 
         Ok(())
@@ -398,12 +439,45 @@ impl Window {
 
     /// Returns the size of the window
     pub fn get_size(&self) -> Option<(u32, u32)> {
-        self.gl_window.get_inner_size()
+        match self.context {
+            Some(ref context) => context.gl_window.get_inner_size(),
+            None => Some((self.size[0], self.size[1])),
+        }
+    }
+
+    /// Set the statistics about the frames rendered in this window
+    pub fn set_frame_stats(&mut self, stats: FrameStats) {
+        self.frame_stats = stats;
+    }
+
+    /// Set how far the game is between the last fixed update and the next
+    pub fn set_alpha(&mut self, alpha: FloatType) {
+        self.alpha = alpha;
     }
 
     /// Get the id of the window
+    ///
+    /// # Panics
+    ///
+    /// If the window is headless, use 'try_id' instead
     pub fn id(&self) -> glutin::WindowId {
-        self.gl_window.id()
+        match self.try_id() {
+            Some(id) => id,
+            None => panic!("A headless window has no id"),
+        }
+    }
+
+    /// Get the id of the window, none if the window is headless
+    pub fn try_id(&self) -> Option<glutin::WindowId> {
+        match self.context {
+            Some(ref context) => Some(context.gl_window.id()),
+            None => None,
+        }
+    }
+
+    /// Return true if the window has no display or OpenGL context
+    pub fn is_headless(&self) -> bool {
+        self.context.is_none()
     }
 }
 
@@ -434,6 +508,12 @@ impl WindowHandle {
     }
 
 
+    /// Return true if the window has no display or OpenGL context
+    pub fn is_headless(&self) -> bool {
+        self.parent.borrow().is_headless()
+    }
+
+
     /// Returns the size of the window
     pub fn get_size(&self) -> [u32; 2] {
         self.parent.borrow().size
@@ -450,20 +530,49 @@ impl WindowHandle {
     }
 
 
+    /// Returns statistics about the frames rendered so far
+    pub fn get_frame_stats(&self) -> FrameStats {
+        self.parent.borrow().frame_stats
+    }
+
+
+    /// Returns how far the game is between the last fixed update and the next, in the range [0, 1).
+    /// Used to interpolate between the last two fixed updates when rendering.
+    /// Always zero when there are no fixed updates.
+    pub fn get_alpha(&self) -> FloatType {
+        self.parent.borrow().alpha
+    }
+
+
     /// Set the title of the window
     pub fn set_title(&mut self, title: &str) {
-        self.parent.borrow().gl_window.set_title(title);
+        if let Some(ref context) = self.parent.borrow().context {
+            context.gl_window.set_title(title);
+        }
     }
 
 
     /// Map window coordinates into the range [-1, 1] (right and top are positive)
     pub fn window_to_ndc(&self, p: Vector2i) -> Vector2 {
-        let size = self.parent.borrow().size;
+        view::window_to_ndc(p.into(), self.get_size())
+    }
+
+
+    /// Map a point in the range [-1, 1] to window coordinates
+    pub fn ndc_to_window(&self, p: Vector2) -> Vector2 {
+        view::ndc_to_window(p, self.get_size())
+    }
+
+
+    /// Map window coordinates, such as the cursor position, into a view's bounds
+    pub fn window_to_world(&self, p: Vector2i, view: &View) -> Vector2 {
+        view.window_to_world(p.into(), self.get_size())
+    }
 
-        let px = p[0] as FloatType / size[0] as FloatType * 2.0 - 1.0;
-        let py = p[1] as FloatType / size[1] as FloatType * 2.0 - 1.0;
 
-        [px, -py].into()
+    /// Map a point within a view's bounds to window coordinates
+    pub fn world_to_window(&self, p: Vector2, view: &View) -> Vector2 {
+        view.world_to_window(p, self.get_size())
     }
 }
 
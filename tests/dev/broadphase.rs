@@ -0,0 +1,65 @@
+use crank::{SpatialGrid, Collide, Bounded};
+use crank::{Rectangle, Circle, Line, Vector2};
+
+
+pub fn spatial_grid() {
+    let mut grid = SpatialGrid::new(Vector2::new(4.0, 4.0));
+
+    // A row of tiles along the x-axis
+    let tiles: Vec<usize> = (0..10)
+        .map(|x| grid.insert(Rectangle::centered(Vector2::new(x as f64 * 2.0, 0.0), Vector2::new(2.0, 2.0))))
+        .collect();
+
+    assert_eq!(grid.len(), 10);
+
+    // A large circle spanning many cells is only returned once
+    let boulder = grid.insert(Circle::new(Vector2::new(10.0, 10.0), 5.0).bounding_box());
+
+    // Regions
+    let region = Rectangle { min: Vector2::new(3.5, -0.5), max: Vector2::new(6.5, 0.5) };
+    assert_eq!(grid.query_region(&region), vec![tiles[2], tiles[3]]);
+    assert_eq!(grid.query_region(&Rectangle::centered(Vector2::new(9.0, 9.0), Vector2::new(1.0, 1.0))), vec![boulder]);
+    assert!(grid.query_region(&Rectangle::centered(Vector2::new(-50.0, 0.0), Vector2::new(1.0, 1.0))).is_empty());
+
+    // Rays
+    let line = Line::new(Vector2::new(6.0, 20.0), Vector2::new(6.0, -20.0));
+    assert_eq!(grid.query_ray(&line), vec![tiles[3], boulder]);
+
+    let short = Line::new(Vector2::new(6.0, 20.0), Vector2::new(6.0, 16.0));
+    assert!(grid.query_ray(&short).is_empty());
+
+    // Swept boxes
+    let player = Rectangle::centered(Vector2::new(0.0, 3.0), Vector2::new(1.0, 1.0));
+    assert_eq!(grid.query_sweep(&player, Vector2::new(0.0, -4.0)), vec![tiles[0]]);
+    assert!(grid.query_sweep(&player, Vector2::new(0.0, 1.0)).is_empty());
+
+    // The candidates can be used for collisions
+    let fallen = Rectangle::centered(Vector2::new(4.0, 0.5), Vector2::new(1.0, 1.0));
+    for handle in grid.query_region(&fallen) {
+        assert!(grid.get(handle).unwrap().intersects(&fallen));
+    }
+
+    // Moving and removing objects
+    grid.set(tiles[0], Rectangle::centered(Vector2::new(0.0, 30.0), Vector2::new(2.0, 2.0)));
+    assert!(grid.query_sweep(&player, Vector2::new(0.0, -4.0)).is_empty());
+
+    assert!(grid.remove(boulder).is_some());
+    assert!(grid.remove(boulder).is_none());
+    assert!(grid.get(boulder).is_none());
+    assert_eq!(grid.query_ray(&line), vec![tiles[3]]);
+    assert_eq!(grid.len(), 10);
+
+    // Removed handles are reused instead of growing the grid
+    let rock = grid.insert(Rectangle::centered(Vector2::new(6.0, 10.0), Vector2::new(1.0, 1.0)));
+    assert_eq!(rock, boulder);
+    assert_eq!(grid.len(), 11);
+    assert_eq!(grid.query_ray(&line), vec![tiles[3], rock]);
+
+    // Objects set again after removing them keep their handle
+    let tile = grid.remove(tiles[3]).unwrap();
+    assert_eq!(grid.len(), 10);
+    grid.set(tiles[3], tile);
+    assert_eq!(grid.len(), 11);
+    assert_ne!(grid.insert(Rectangle::default()), tiles[3]);
+    assert_eq!(grid.len(), 12);
+}
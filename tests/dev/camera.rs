@@ -0,0 +1,92 @@
+use crank::{Camera, View, Vector2};
+
+use std::f64::consts::PI;
+
+
+pub fn camera() {
+    // A wide viewport shows more of the world horizontally
+    let mut camera = Camera::new(Vector2::new(10.0, 5.0), 4.0).with_aspect(2.0);
+    assert_eq!(camera.get_size(), Vector2::new(8.0, 4.0));
+    assert_eq!(camera.world_to_ndc(Vector2::new(14.0, 7.0)), Vector2::new(1.0, 1.0));
+    assert_eq!(camera.ndc_to_world(Vector2::new(-1.0, 0.0)), Vector2::new(6.0, 5.0));
+
+    camera.set_viewport_size(300, 300);
+    assert_eq!(camera.aspect, 1.0);
+
+    // Zooming in shows less of the world
+    camera.zoom = 2.0;
+    assert_eq!(camera.get_size(), Vector2::new(2.0, 2.0));
+    assert_eq!(camera.world_to_ndc(Vector2::new(11.0, 5.0)), Vector2::new(1.0, 0.0));
+
+    // Turning the camera left makes the world turn right
+    let camera = Camera::new(Vector2::zero(), 2.0).with_rotation(PI / 2.0);
+    assert!(approx(camera.world_to_ndc(Vector2::new(0.0, 1.0)), Vector2::new(1.0, 0.0)));
+    assert!(approx(camera.ndc_to_world(Vector2::new(1.0, 0.0)), Vector2::new(0.0, 1.0)));
+
+    let bounds = Camera::new(Vector2::zero(), 2.0).with_rotation(PI / 4.0).get_bounds();
+    assert!(approx(bounds.max, Vector2::new(2.0f64.sqrt(), 2.0f64.sqrt())));
+
+    // Batches cull with the same bounds, turned a quarter the corners swap places
+    let _software_textures = crank::SoftwareTextureScope::new();
+    let mut batch = crank::RenderBatch::new();
+    batch.set_view(Camera::new(Vector2::new(1.0, 0.0), 2.0).with_aspect(2.0).with_rotation(PI / 2.0));
+
+    let bounds = batch.get_view_bounds();
+    assert!(approx(bounds.min, Vector2::new(0.0, -2.0)));
+    assert!(approx(bounds.max, Vector2::new(2.0, 2.0)));
+
+    // Following covers the same fraction of the distance, no matter how the time is split
+    let mut slow = Camera::new(Vector2::zero(), 2.0);
+    let mut fast = Camera::new(Vector2::zero(), 2.0);
+
+    slow.follow(Vector2::new(10.0, 0.0), 2.0, 0.5);
+    for _ in 0..5 {
+        fast.follow(Vector2::new(10.0, 0.0), 2.0, 0.1);
+    }
+
+    assert!(approx(slow.center, fast.center));
+    assert!(slow.center.x > 0.0 && slow.center.x < 10.0);
+
+    // Shaking moves the view around the center, then fades out
+    let mut camera = Camera::new(Vector2::zero(), 2.0);
+    assert!(!camera.is_shaking());
+
+    camera.shake(0.5, 1.0);
+    camera.update(0.1);
+    assert!(camera.is_shaking());
+
+    let offset = camera.get_shake_offset();
+    assert!(offset.length() > 0.0 && offset.length() <= 0.5);
+    assert!(approx(camera.ndc_to_world(Vector2::zero()), offset));
+
+    camera.update(1.0);
+    assert!(!camera.is_shaking());
+    assert_eq!(camera.get_shake_offset(), Vector2::zero());
+}
+
+
+pub fn window_conversions() {
+    use crank::{Rectangle, window_to_ndc, ndc_to_window};
+
+    // Window pixels start in the top left corner and grow downwards
+    assert_eq!(window_to_ndc(Vector2::new(0.0, 0.0), [200, 100]), Vector2::new(-1.0, 1.0));
+    assert_eq!(window_to_ndc(Vector2::new(200.0, 100.0), [200, 100]), Vector2::new(1.0, -1.0));
+    assert_eq!(ndc_to_window(Vector2::new(0.0, 0.0), [200, 100]), Vector2::new(100.0, 50.0));
+
+    let view = Rectangle { min: Vector2::new(0.0, 0.0), max: Vector2::new(20.0, 10.0) };
+    assert!(approx(view.window_to_world(Vector2::new(50.0, 25.0), [200, 100]), Vector2::new(5.0, 7.5)));
+    assert!(approx(view.world_to_window(Vector2::new(5.0, 7.5), [200, 100]), Vector2::new(50.0, 25.0)));
+
+    // Conversions through a rotated camera round trip
+    let camera = Camera::new(Vector2::new(3.0, -2.0), 4.0).with_rotation(PI / 3.0).with_aspect(2.0);
+    let pixel = Vector2::new(37.0, 81.0);
+    let world = camera.window_to_world(pixel, [200, 100]);
+    assert!(approx(camera.world_to_window(world, [200, 100]), pixel));
+    assert!(approx(camera.world_to_ndc(world), window_to_ndc(pixel, [200, 100])));
+}
+
+
+/// Return true if two points are almost the same
+fn approx(a: Vector2, b: Vector2) -> bool {
+    (a - b).length() < 1e-9
+}
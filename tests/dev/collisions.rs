@@ -2,13 +2,11 @@ use crank;
 
 const SCALE: f64 = 1.0;
 
-
-use self::super::frame_counter::FrameCounter;
-
 pub fn run() {
     let settings = crank::GameSettings {
         vertical_sync: true,
         clear_color: [0.2; 4],
+        fixed_update: None,
     };
 
     crank::run_game::<Game>(720, 720, "Collisions", settings).unwrap();
@@ -19,8 +17,6 @@ struct Game {
     running: bool,
     window: crank::WindowHandle,
 
-    frame_counter: FrameCounter,
-
     batch: crank::RenderBatch,
     view: crank::Rectangle,
 
@@ -37,7 +33,7 @@ struct Game {
 impl Game {
     fn tick(&mut self, dt: f64) {
         if self.window.mouse_down(crank::MouseButton::Right) {
-            let mouse = self.mouse_to_world(self.window.get_cursor_position());
+            let mouse = self.window.window_to_world(self.window.get_cursor_position(), &self.view);
 
             if self.window.key_down(crank::KeyCode::LShift) {
                 self.line.start = mouse;
@@ -47,7 +43,7 @@ impl Game {
         }
 
         if self.window.mouse_down(crank::MouseButton::Middle) {
-            let mouse = self.mouse_to_world(self.window.get_cursor_position());
+            let mouse = self.window.window_to_world(self.window.get_cursor_position(), &self.view);
 
             self.sweep_start = mouse;
         }
@@ -122,12 +118,6 @@ impl Game {
             self.batch.draw_line(&self.line, line_width);
         }
     }
-
-
-    fn mouse_to_world(&self, mouse: crank::Vector2i) -> crank::Vector2 {
-        use crank::View;
-        self.view.ndc_to_world(self.window.window_to_ndc(mouse))
-    }
 }
 
 
@@ -137,8 +127,6 @@ impl crank::Game for Game {
             running: true,
             window,
 
-            frame_counter: FrameCounter::new(),
-
             batch: crank::RenderBatch::new(),
             view: crank::Rectangle::centered(crank::Vector2::new(0.0, 0.0), crank::Vector2::new(2.0, 2.0)),
 
@@ -163,8 +151,8 @@ impl crank::Game for Game {
 
         self.draw();
 
-        if let Some(fps) = self.frame_counter.tick() {
-            self.window.set_title(&format!("FPS: {}", fps));
+        if info.stats.frame % 30 == 0 {
+            self.window.set_title(&format!("FPS: {}", info.stats.fps.round()));
         }
     }
 
@@ -186,7 +174,7 @@ impl crank::WindowEventHandler for Game {
 
     fn mouse_moved(&mut self, x: i64, y: i64) {
         if self.window.mouse_down(crank::MouseButton::Left) {
-            self.rect_b = crank::Rectangle::centered(self.mouse_to_world([x, y].into()), self.rect_b.size());
+            self.rect_b = crank::Rectangle::centered(self.window.window_to_world([x, y].into(), &self.view), self.rect_b.size());
         }
     }
 
@@ -0,0 +1,334 @@
+
+use crank;
+
+use crank::{RenderShape, Renderer, Rectangle, Vector2};
+
+use std::cell::RefCell;
+
+
+pub fn run() {
+    let settings = crank::HeadlessSettings {
+        clear_color: [0.2; 4],
+        time_steps: crank::TimeSteps::Fixed { dt: 0.25, frames: 30 },
+        fixed_update: Some(crank::FixedUpdate { tick_rate: 8.0, max_steps: 4 }),
+    };
+
+    let run = crank::run_game_headless::<Game, _>(320, 240, settings, crank::RecordingRenderer::new());
+
+    // The game stops itself after 10 frames, each running two fixed updates
+    assert_eq!(run.program.frames, 10);
+    assert_eq!(run.program.fixed_updates, 20);
+    assert_eq!(run.program.window.get_size(), [320, 240]);
+
+    let batches = run.renderer.get_commands().iter()
+        .filter(|command| match **command {
+            crank::RenderCommand::SubmitBatch(_) => true,
+            _ => false
+        })
+        .count();
+
+    assert_eq!(batches, 10);
+
+    // Every frame took a quarter of a second
+    let stats = run.program.window.get_frame_stats();
+    assert_eq!(stats.frame, 9);
+    assert_eq!(stats.time, 2.5);
+    assert_eq!(stats.fps, 4.0);
+    assert_eq!(stats.min_frame_time, 0.25);
+    assert_eq!(stats.max_frame_time, 0.25);
+}
+
+
+pub fn frame_stats() {
+    let settings = crank::HeadlessSettings {
+        clear_color: [0.2; 4],
+        time_steps: crank::TimeSteps::Scripted(vec![0.5, 0.25, 0.25, 0.5, 0.125]),
+        fixed_update: None,
+    };
+
+    let run = crank::run_game_headless::<Game, _>(320, 240, settings, crank::RecordingRenderer::new());
+    let stats = &run.program.stats;
+
+    assert_eq!(stats.len(), 5);
+    assert_eq!(stats[0].frame, 0);
+    assert_eq!(stats[0].fps, 2.0);
+
+    // The first frame no longer fits within the last second
+    assert_eq!(stats[3].frame, 3);
+    assert_eq!(stats[3].time, 1.5);
+    assert_eq!(stats[3].fps, 3.0);
+    assert_eq!(stats[3].min_frame_time, 0.25);
+    assert_eq!(stats[3].max_frame_time, 0.5);
+
+    assert_eq!(stats[4].fps, 4.0 / 1.125);
+    assert_eq!(stats[4].min_frame_time, 0.125);
+    assert_eq!(stats[4].max_frame_time, 0.5);
+
+    assert_eq!(run.program.window.get_frame_stats(), stats[4]);
+
+    // Apps count every time they are refreshed
+    let settings = crank::HeadlessSettings {
+        clear_color: [0.2; 4],
+        time_steps: crank::TimeSteps::Scripted(vec![0.5, 0.25, 0.25, 0.5, 0.125]),
+        fixed_update: None,
+    };
+
+    let run = crank::run_app_headless::<App, _>(320, 240, settings, crank::RecordingRenderer::new());
+    assert_eq!(run.program.window.get_frame_stats(), stats[4]);
+}
+
+
+pub fn render_alpha() {
+    let settings = crank::HeadlessSettings {
+        clear_color: [0.2; 4],
+        time_steps: crank::TimeSteps::Scripted(vec![0.0625, 0.03125, 0.125, 0.03125]),
+        fixed_update: Some(crank::FixedUpdate { tick_rate: 8.0, max_steps: 4 }),
+    };
+
+    let run = crank::run_game_headless::<Game, _>(320, 240, settings, crank::RecordingRenderer::new());
+
+    // Rendering sees how far time is between fixed updates
+    assert_eq!(run.program.fixed_updates, 2);
+    assert_eq!(*run.program.rendered_alphas.borrow(), vec![0.5, 0.75, 0.75, 0.0]);
+    assert_eq!(run.program.window.get_alpha(), 0.0);
+
+    // Without fixed updates there is nothing to interpolate
+    let settings = crank::HeadlessSettings {
+        clear_color: [0.2; 4],
+        time_steps: crank::TimeSteps::Fixed { dt: 0.1, frames: 3 },
+        fixed_update: None,
+    };
+
+    let run = crank::run_game_headless::<Game, _>(320, 240, settings, crank::RecordingRenderer::new());
+    assert_eq!(*run.program.rendered_alphas.borrow(), vec![0.0; 3]);
+}
+
+
+pub fn rasterize() {
+    // Textures are only kept in main memory while the scope lives
+    assert!(!crank::software_textures_enabled());
+    let software_textures = crank::SoftwareTextureScope::new();
+
+    let mut rasterizer = crank::Rasterizer::new(100, 100);
+    rasterizer.set_clear_color([0.0, 0.0, 0.0, 1.0]);
+    rasterizer.clear();
+
+    let mut batch = crank::RenderBatch::new();
+    batch.set_view(Rectangle::centered(Vector2::zero(), Vector2::new(2.0, 2.0)));
+
+    // An opaque red square in the middle
+    batch.set_color([1.0, 0.0, 0.0, 1.0]);
+    batch.fill_rectangle(&Rectangle::centered(Vector2::zero(), Vector2::new(1.0, 1.0)));
+
+    // A half transparent blue square in the top right corner, above the red one
+    batch.set_color([0.0, 0.0, 1.0, 0.5]);
+    batch.fill_rectangle(&Rectangle { min: Vector2::new(0.0, 0.0), max: Vector2::new(1.0, 1.0) });
+
+    rasterizer.submit_batch(&batch);
+    let image = rasterizer.get_image();
+
+    // Alpha is blended the same way as color
+    assert_eq!(image.get_pixel(90, 10), [0, 0, 128, 191]);
+    assert_eq!(image.get_pixel(60, 40), [128, 0, 128, 191]);
+    assert_eq!(image.get_pixel(35, 65), [255, 0, 0, 255]);
+    assert_eq!(image.get_pixel(5, 5), [0, 0, 0, 255]);
+
+    // Textures created in the scope are still sampled afterwards
+    drop(software_textures);
+    assert!(!crank::software_textures_enabled());
+
+    rasterizer.clear();
+    rasterizer.submit_batch(&batch);
+    assert_eq!(rasterizer.get_image().get_pixel(35, 65), [255, 0, 0, 255]);
+
+    // Running without a window doesn't leave textures in main memory
+    let settings = crank::HeadlessSettings {
+        clear_color: [0.2; 4],
+        time_steps: crank::TimeSteps::Fixed { dt: 0.1, frames: 1 },
+        fixed_update: None,
+    };
+
+    crank::run_game_headless::<Game, _>(10, 10, settings, crank::RecordingRenderer::new());
+    assert!(!crank::software_textures_enabled());
+}
+
+
+pub fn renderer_trait() {
+    // Textures have to be kept in main memory before the batch is created
+    let _software_textures = crank::SoftwareTextureScope::new();
+    let mut rasterizer = crank::Rasterizer::new(10, 10);
+    let mut recorder = crank::RecordingRenderer::new();
+
+    let mut batch = crank::RenderBatch::new();
+    batch.set_view(Rectangle::centered(Vector2::zero(), Vector2::new(2.0, 2.0)));
+    batch.set_color([0.0, 1.0, 0.0, 1.0]);
+    batch.fill_rectangle(&Rectangle { min: Vector2::new(-1.0, -1.0), max: Vector2::new(0.0, 0.0) });
+
+    // The same drawing code works for any backend
+    draw_frame(&mut rasterizer, &batch);
+    draw_frame(&mut recorder, &batch);
+
+    let commands = recorder.get_commands();
+    assert_eq!(commands.len(), 4);
+
+    match commands[3] {
+        crank::RenderCommand::SubmitBatch(ref recorded) => {
+            assert_eq!(recorded.meshes.len(), 1);
+            assert_eq!(recorded.meshes[0].vertices.len(), 4);
+
+            // Drawing the recording gives the same image as drawing the batch
+            let mut replay = crank::Rasterizer::new(10, 10);
+            replay.set_clear_color([0.0, 0.0, 1.0, 1.0]);
+            replay.clear();
+            replay.draw_recorded_batch(recorded);
+
+            assert_eq!(replay.get_image().get_pixel(2, 8), rasterizer.get_image().get_pixel(2, 8));
+        }
+
+        ref command => panic!("Expected a batch, got {:?}", command)
+    }
+
+    // Only the bottom left quarter is drawn
+    let image = rasterizer.get_image();
+    assert_eq!(image.get_pixel(2, 8), [0, 255, 0, 255]);
+    assert_eq!(image.get_pixel(8, 2), [0, 0, 255, 255]);
+}
+
+
+/// Clear the screen and draw a batch, in the same way for every renderer
+fn draw_frame(renderer: &mut Renderer, batch: &crank::RenderBatch) {
+    renderer.set_viewport(0, 0, 10, 10);
+    renderer.set_clear_color([0.0, 0.0, 1.0, 1.0]);
+    renderer.clear();
+    renderer.submit_batch(batch);
+}
+
+
+pub fn transform_stack() {
+    use crank::Transform;
+    use std::f64::consts::PI;
+
+    // Textures have to be kept in main memory before the batch is created
+    let _software_textures = crank::SoftwareTextureScope::new();
+    let mut rasterizer = crank::Rasterizer::new(100, 100);
+    rasterizer.set_clear_color([0.0, 0.0, 0.0, 1.0]);
+    rasterizer.clear();
+
+    let mut batch = crank::RenderBatch::new();
+    batch.set_view(Rectangle::centered(Vector2::zero(), Vector2::new(2.0, 2.0)));
+
+    // A thin bar rotated to stand upright, then moved to the right
+    batch.push_transform(Transform::translate(Vector2::new(0.5, 0.0)));
+    batch.push_transform(Transform::rotate(PI / 2.0));
+    batch.fill_rectangle(&Rectangle::centered(Vector2::zero(), Vector2::new(1.6, 0.2)));
+
+    assert!((batch.get_transform().transform_point(Vector2::new(1.0, 0.0)) - Vector2::new(0.5, 1.0)).length() < 1e-9);
+
+    // Only the rotation is undone
+    batch.pop_transform();
+    batch.fill_triangle(&crank::Triangle::new(Vector2::new(-0.5, -0.9), Vector2::new(-0.4, -0.9), Vector2::new(-0.5, -0.8)));
+
+    // Nothing is applied once all transforms are popped
+    batch.pop_transform();
+    batch.pop_transform();
+    assert_eq!(batch.get_transform(), Transform::identity());
+    batch.fill_rectangle(&Rectangle { min: Vector2::new(-1.0, 0.8), max: Vector2::new(-0.8, 1.0) });
+
+    let mut renderer = crank::RecordingRenderer::new();
+    renderer.submit_batch(&batch);
+
+    let vertices = match renderer.get_commands()[0] {
+        crank::RenderCommand::SubmitBatch(ref batch) => batch.meshes[0].vertices.clone(),
+        ref command => panic!("Expected a batch, got {:?}", command)
+    };
+
+    assert!((vertices[0].position[0] - 0.6).abs() < 1e-6 && (vertices[0].position[1] + 0.8).abs() < 1e-6);
+    assert!((vertices[4].position[0] - 0.0).abs() < 1e-6 && (vertices[4].position[1] + 0.9).abs() < 1e-6);
+    assert_eq!(vertices[7].position[0], -1.0);
+
+    rasterizer.submit_batch(&batch);
+    let image = rasterizer.get_image();
+
+    // The bar is upright
+    assert_eq!(image.get_pixel(75, 20), [255, 255, 255, 255]);
+    assert_eq!(image.get_pixel(75, 80), [255, 255, 255, 255]);
+    assert_eq!(image.get_pixel(60, 50), [0, 0, 0, 255]);
+    assert_eq!(image.get_pixel(2, 2), [255, 255, 255, 255]);
+}
+
+
+struct Game {
+    window: crank::WindowHandle,
+
+    batch: crank::RenderBatch,
+    frames: u32,
+    fixed_updates: u32,
+    position: Vector2,
+    stats: Vec<crank::FrameStats>,
+
+    // The interpolation alpha of every rendered frame
+    rendered_alphas: RefCell<Vec<f64>>,
+}
+
+
+impl crank::Game for Game {
+    fn setup(window: crank::WindowHandle) -> Self {
+        Game {
+            window,
+
+            batch: crank::RenderBatch::new(),
+            frames: 0,
+            fixed_updates: 0,
+            position: Vector2::zero(),
+            stats: Vec::new(),
+
+            rendered_alphas: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn fixed_update(&mut self, info: crank::UpdateInfo) {
+        self.fixed_updates += 1;
+        self.position += Vector2::new(info.dt, 0.0);
+    }
+
+    fn update(&mut self, info: crank::UpdateInfo) {
+        self.frames += 1;
+        self.stats.push(info.stats);
+
+        self.batch.clear();
+        self.batch.set_color([1.0, 0.0, 0.0, 1.0]);
+        self.batch.fill_rectangle(&Rectangle::centered(self.position, Vector2::new(0.5, 0.5)));
+    }
+
+    fn render(&self, renderer: &mut Renderer) {
+        self.rendered_alphas.borrow_mut().push(self.window.get_alpha());
+        renderer.submit_batch(&self.batch);
+    }
+
+    fn is_running(&self) -> bool {
+        self.frames < 10
+    }
+}
+
+
+impl crank::WindowEventHandler for Game {}
+
+
+struct App {
+    window: crank::WindowHandle,
+}
+
+
+impl crank::App for App {
+    fn setup(window: crank::WindowHandle) -> Self {
+        App {
+            window,
+        }
+    }
+
+    fn render(&self, renderer: &mut Renderer) {}
+}
+
+
+impl crank::WindowEventHandler for App {}
+impl crank::WindowFileHandler for App {}
@@ -0,0 +1,102 @@
+use crank::{Transform, Vector2, View, Rectangle, OrientedRectangle};
+
+use std::f64::consts::PI;
+
+
+pub fn transform() {
+    let point = Vector2::new(2.0, 1.0);
+
+    assert_eq!(Transform::identity().transform_point(point), point);
+    assert_eq!(Transform::translate(Vector2::new(1.0, -1.0)).transform_point(point), Vector2::new(3.0, 0.0));
+    assert_eq!(Transform::scale(Vector2::new(2.0, 3.0)).transform_point(point), Vector2::new(4.0, 3.0));
+    assert!(approx(Transform::rotate(PI / 2.0).transform_point(point), Vector2::new(-1.0, 2.0)));
+
+    // Vectors are not moved
+    assert_eq!(Transform::translate(Vector2::new(1.0, -1.0)).transform_vector(point), point);
+
+    // Scale first, then rotate, then move
+    let transform = Transform::scale(Vector2::new(2.0, 2.0))
+        .then(Transform::rotate(PI / 2.0))
+        .then(Transform::translate(Vector2::new(10.0, 0.0)));
+
+    assert!(approx(transform.transform_point(point), Vector2::new(8.0, 4.0)));
+    assert_eq!(
+        transform,
+        Transform::translate(Vector2::new(10.0, 0.0)) * Transform::rotate(PI / 2.0) * Transform::scale(Vector2::new(2.0, 2.0))
+    );
+
+    // Undo the transform
+    let inverse = transform.inverse().unwrap();
+    assert!(approx(inverse.transform_point(Vector2::new(8.0, 4.0)), point));
+    assert!(approx((inverse * transform).transform_point(Vector2::new(-3.0, 7.0)), Vector2::new(-3.0, 7.0)));
+    assert!(Transform::scale(Vector2::new(1.0, 0.0)).inverse().is_none());
+
+    assert_eq!(transform.to_matrix()[2], [10.0, 0.0, 1.0]);
+
+    // Views map their bounds onto the range [-1, 1]
+    let view = Rectangle { min: Vector2::new(0.0, 0.0), max: Vector2::new(4.0, 2.0) };
+    assert_eq!(view.get_transform().transform_point(Vector2::new(4.0, 2.0)), Vector2::new(1.0, 1.0));
+    assert_eq!(view.get_transform().transform_point(Vector2::new(1.0, 0.5)), Vector2::new(-0.5, -0.5));
+    assert_eq!(view.ndc_to_world(Vector2::new(-1.0, 1.0)), Vector2::new(0.0, 2.0));
+
+    // Views that collapse the world can't be undone
+    let flat = FlatView;
+    assert_eq!(flat.world_to_ndc(Vector2::new(3.0, 4.0)), Vector2::new(3.0, 0.0));
+    assert_eq!(flat.ndc_to_world(Vector2::new(0.5, 0.5)), Vector2::new(0.5, 0.5));
+
+    // Views that only describe their translation and scaling still work
+    let legacy = LegacyView;
+    assert_eq!(legacy.world_to_ndc(Vector2::new(4.0, 2.0)), Vector2::new(1.0, 1.0));
+    assert_eq!(legacy.ndc_to_world(Vector2::new(-1.0, -1.0)), Vector2::new(0.0, 0.0));
+
+    #[allow(deprecated)]
+    let transformation = view.get_transformation();
+    assert_eq!(transformation, (Vector2::new(-2.0, -1.0), Vector2::new(0.5, 1.0)));
+
+    // Transforming shapes
+    let square = Rectangle { min: Vector2::new(0.0, 0.0), max: Vector2::new(2.0, 2.0) };
+    let moved = square.transformed(&Transform::translate(Vector2::new(1.0, 0.0)));
+    assert_eq!(moved.points[0], Vector2::new(1.0, 0.0));
+    assert_eq!(moved.points[2], Vector2::new(3.0, 2.0));
+
+    // Mirrored corners are still counter-clockwise
+    let mirrored = square.transformed(&Transform::scale(Vector2::new(-1.0, 1.0)));
+    assert!(mirrored.contains(Vector2::new(-1.0, 1.0)));
+    assert!(!mirrored.contains(Vector2::new(1.0, 1.0)));
+
+    // A rotated rectangle that is stretched becomes a rhombus
+    let diamond = OrientedRectangle::new(Vector2::zero(), Vector2::new(1.0, 1.0), PI / 4.0);
+    let rhombus = diamond.transformed(&Transform::scale(Vector2::new(2.0, 1.0)));
+    assert!(approx(rhombus.points[1], Vector2::new(2.0 * 2.0f64.sqrt(), 0.0)));
+    assert!(rhombus.contains(Vector2::new(2.5, 0.0)));
+    assert!(!diamond.contains(Vector2::new(2.5, 0.0)));
+
+    let polygon = rhombus.transformed(&Transform::rotate(PI / 2.0));
+    assert!(approx(polygon.points[1], Vector2::new(0.0, 2.0 * 2.0f64.sqrt())));
+}
+
+
+/// A view that squashes the world onto the x axis
+struct FlatView;
+
+impl View for FlatView {
+    fn get_transform(&self) -> Transform {
+        Transform::scale(Vector2::new(1.0, 0.0))
+    }
+}
+
+
+/// A view of the rectangle from (0, 0) to (4, 2) that only describes its translation and scaling
+struct LegacyView;
+
+impl View for LegacyView {
+    fn get_transformation(&self) -> (Vector2, Vector2) {
+        (Vector2::new(-2.0, -1.0), Vector2::new(0.5, 1.0))
+    }
+}
+
+
+/// Return true if two points are almost the same
+fn approx(a: Vector2, b: Vector2) -> bool {
+    (a - b).length() < 1e-9
+}
@@ -1,5 +1,8 @@
-
-pub mod frame_counter;
-
 pub mod collisions;
-pub mod platformer;
\ No newline at end of file
+pub mod platformer;
+pub mod headless;
+pub mod shapes;
+pub mod broadphase;
+pub mod physics;
+pub mod linear;
+pub mod camera;
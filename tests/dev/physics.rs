@@ -0,0 +1,631 @@
+use crank::{PhysicsWorld, RigidBody, ContactTarget, PhysicsObject, CollisionFilter, TriggerPhase};
+use crank::{Rectangle, Vector2, Sweep, cast_shape};
+
+
+pub fn world() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+
+    let floor = world.add_static(Rectangle { min: Vector2::new(-10.0, -1.0), max: Vector2::new(10.0, 0.0) });
+
+    let falling = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(-3.0, 2.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(0.0, -10.0))
+    );
+
+    // Two bodies sliding into each other along the floor
+    let left = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(2.0, 0.5), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(4.0, 0.0))
+    );
+    let right = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(4.0, 0.5), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(-4.0, 0.0))
+    );
+
+    assert_eq!(world.get_body_handles(), vec![falling, left, right]);
+
+    // Nothing touches during the first step
+    assert!(world.tick(0.1).is_empty());
+
+    let events = world.tick(0.1);
+    assert_eq!(events.len(), 3);
+
+    // The falling body lands on the floor
+    assert_eq!(events[0].body, falling);
+    assert_eq!(events[0].other, ContactTarget::Static(floor));
+    assert_eq!(events[0].normal, Vector2::new(0.0, 1.0));
+
+    let body = world.get_body(falling).unwrap();
+    assert_eq!(body.collider.center(), Vector2::new(-3.0, 0.5));
+    assert_eq!(body.velocity, Vector2::zero());
+
+    // The sliding bodies are pushed apart and stop
+    assert_eq!(events[1].body, left);
+    assert_eq!(events[1].other, ContactTarget::Body(right));
+    assert_eq!(events[1].normal, Vector2::new(-1.0, 0.0));
+    assert_eq!(events[2].body, right);
+    assert_eq!(events[2].other, ContactTarget::Body(left));
+
+    let (a, b) = (world.get_body(left).unwrap(), world.get_body(right).unwrap());
+    assert!((a.collider.max.x - b.collider.min.x).abs() < 1e-9);
+    assert_eq!(a.velocity, Vector2::zero());
+    assert_eq!(b.velocity, Vector2::zero());
+
+    // Removed bodies are no longer stepped
+    assert!(world.remove_body(falling).is_some());
+    assert!(world.get_body(falling).is_none());
+    assert_eq!(world.get_body_handles(), vec![left, right]);
+
+    assert!(world.remove_static(floor).is_some());
+    world.get_body_mut(left).unwrap().velocity = Vector2::new(0.0, -1.0);
+    assert!(world.tick(1.0).is_empty());
+    assert_eq!(world.get_body(left).unwrap().collider.center().y, -0.5);
+
+    // A body sliding along the floor is stopped by a wall its initial path missed
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    let floor = world.add_static(Rectangle { min: Vector2::new(-10.0, -1.0), max: Vector2::new(10.0, 0.0) });
+    let wall = world.add_static(Rectangle { min: Vector2::new(3.0, 0.5), max: Vector2::new(4.0, 2.0) });
+
+    let sliding = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.6), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(40.0, -40.0))
+    );
+
+    let events = world.tick(0.1);
+    assert_eq!(events[0].other, ContactTarget::Static(floor));
+    assert_eq!(events[1].other, ContactTarget::Static(wall));
+
+    let body = world.get_body(sliding).unwrap();
+    assert!(approx(body.collider.max.x, 3.0));
+    assert!(approx(body.collider.min.y, 0.0));
+
+    // A bouncy body turns around and is stopped by the wall right behind where it started
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    let front = world.add_static(Rectangle { min: Vector2::new(0.6, -1.0), max: Vector2::new(1.6, 1.0) });
+    let back = world.add_static(Rectangle { min: Vector2::new(-1.6, -1.0), max: Vector2::new(-0.6, 1.0) });
+
+    let bouncy = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::zero(), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(40.0, 0.0))
+            .with_restitution(1.0)
+    );
+
+    let events = world.tick(0.1);
+    assert_eq!(events[0].other, ContactTarget::Static(front));
+    assert_eq!(events[1].other, ContactTarget::Static(back));
+
+    let body = world.get_body(bouncy).unwrap();
+    assert!(body.collider.min.x >= -0.6 - 1e-9 && body.collider.max.x <= 0.6 + 1e-9);
+}
+
+
+pub fn materials() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    world.add_static(Rectangle { min: Vector2::new(-10.0, -1.0), max: Vector2::new(10.0, 0.0) });
+
+    let bouncy = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(-6.0, 2.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(0.0, -10.0))
+            .with_restitution(0.5)
+    );
+
+    let sliding = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(-3.0, 2.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(4.0, -10.0))
+            .with_friction(0.0, 0.2)
+    );
+
+    let sticky = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(3.0, 2.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(4.0, -10.0))
+            .with_friction(0.5, 0.2)
+    );
+
+    world.tick(0.1);
+    world.tick(0.1);
+
+    // Bounced back up with half the speed, for the remaining half of the step
+    let body = world.get_body(bouncy).unwrap();
+    assert_eq!(body.velocity, Vector2::new(0.0, 5.0));
+    assert_eq!(body.collider.center().y, 0.75);
+
+    // Slowed down by the impact
+    assert_eq!(world.get_body(sliding).unwrap().velocity, Vector2::new(2.0, 0.0));
+    assert_eq!(world.get_body(sticky).unwrap().velocity, Vector2::zero());
+}
+
+
+pub fn mass() {
+    let mut body = RigidBody::new(Rectangle::default()).with_mass(2.0);
+    body.apply_impulse(Vector2::new(2.0, 0.0));
+    assert_eq!(body.velocity, Vector2::new(1.0, 0.0));
+
+    // Forces act over time
+    body.apply_force(Vector2::new(2.0, 0.0), 0.5);
+    assert_eq!(body.velocity, Vector2::new(1.5, 0.0));
+
+    let mut wall = RigidBody::new(Rectangle::default()).with_mass(::std::f64::INFINITY);
+    wall.apply_impulse(Vector2::new(2.0, 0.0));
+    wall.apply_force(Vector2::new(2.0, 0.0), 1.0);
+    assert_eq!(wall.velocity, Vector2::zero());
+
+    // Drag slows bodies down the same, no matter their mass
+    for &mass in [0.5, 4.0, ::std::f64::INFINITY].iter() {
+        let mut body = RigidBody::new(Rectangle::default())
+            .with_mass(mass)
+            .with_velocity(Vector2::new(4.0, 0.0))
+            .with_drag(Vector2::new(0.5, 0.5));
+
+        body.update_velocity(0.5);
+        assert_eq!(body.velocity, Vector2::new(3.0, 0.0));
+    }
+
+    // A heavy body pushes a light one along
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+
+    let heavy = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(2.0, 0.0))
+            .with_mass(3.0)
+    );
+
+    let light = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(1.2, 0.0), Vector2::new(1.0, 1.0)))
+    );
+
+    assert!(world.tick(0.1).is_empty());
+    assert_eq!(world.tick(0.1).len(), 2);
+
+    // Momentum is conserved
+    assert!((world.get_body(heavy).unwrap().velocity.x - 1.5).abs() < 1e-9);
+    assert!((world.get_body(light).unwrap().velocity.x - 1.5).abs() < 1e-9);
+
+    // The light body was pushed three times as far
+    assert!((world.get_body(heavy).unwrap().collider.max.x - 0.85).abs() < 1e-9);
+    assert!((world.get_body(light).unwrap().collider.min.x - 0.85).abs() < 1e-9);
+}
+
+
+pub fn gravity() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    world.set_gravity(Vector2::new(0.0, -10.0));
+
+    let coarse = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::zero(), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(2.0, 5.0))
+    );
+
+    let floating = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(0.0, 10.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(2.0, 5.0))
+            .with_gravity_scale(0.0)
+    );
+
+    // The same arc, stepped with a smaller time step
+    let mut fine = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    fine.set_gravity(world.get_gravity());
+
+    let fine_body = fine.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::zero(), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(2.0, 5.0))
+            .with_mass(4.0)
+    );
+
+    world.tick(0.5);
+    for _ in 0..4 {
+        fine.tick(0.125);
+    }
+
+    // Half a second later the projectile reached the top of its arc, regardless of time step or mass
+    assert_eq!(world.get_body(coarse).unwrap().velocity, Vector2::new(2.0, 0.0));
+    assert_eq!(fine.get_body(fine_body).unwrap().velocity, Vector2::new(2.0, 0.0));
+    assert_eq!(world.get_body(floating).unwrap().velocity, Vector2::new(2.0, 5.0));
+
+    // Bodies report the gravity they are pulled by
+    assert_eq!(world.get_body(coarse).unwrap().get_gravity(), Vector2::new(0.0, -10.0));
+    assert_eq!(world.get_body(floating).unwrap().get_gravity(), Vector2::zero());
+
+    world.set_gravity(Vector2::new(0.0, -20.0));
+    assert_eq!(world.get_body(coarse).unwrap().get_gravity(), Vector2::new(0.0, -20.0));
+}
+
+
+pub fn contacts() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    world.set_gravity(Vector2::new(0.0, -20.0));
+
+    let floor = world.add_static(Rectangle { min: Vector2::new(-10.0, -1.0), max: Vector2::new(10.0, 0.0) });
+    let wall = world.add_static(Rectangle { min: Vector2::new(3.0, 0.0), max: Vector2::new(4.0, 5.0) });
+    let ceiling = world.add_static(Rectangle { min: Vector2::new(-10.0, 5.0), max: Vector2::new(10.0, 6.0) });
+
+    let standing = world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.5), Vector2::new(1.0, 1.0))));
+
+    let sliding = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(2.5, 2.5), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(5.0, 0.0))
+    );
+
+    let jumping = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(-5.0, 4.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(0.0, 20.0))
+    );
+
+    world.tick(0.1);
+
+    let contacts = &world.get_body(standing).unwrap().contacts;
+    assert!(contacts.on_ground());
+    assert!(!contacts.on_wall() && !contacts.on_ceiling());
+    assert_eq!(contacts.get_ground().unwrap().target, ContactTarget::Static(floor));
+    assert_eq!(contacts.get_ground().unwrap().impact.time, 0.0);
+
+    let contacts = &world.get_body(sliding).unwrap().contacts;
+    assert!(contacts.on_wall());
+    assert!(!contacts.on_ground());
+    assert_eq!(contacts.get_wall().unwrap().target, ContactTarget::Static(wall));
+    assert_eq!(contacts.get_wall_normal(), Some(Vector2::new(-1.0, 0.0)));
+
+    let contacts = &world.get_body(jumping).unwrap().contacts;
+    assert!(contacts.on_ceiling());
+    assert_eq!(contacts.len(), 1);
+    assert_eq!(contacts.iter().next().unwrap().target, ContactTarget::Static(ceiling));
+
+    // Contacts only last for a single step
+    world.get_body_mut(standing).unwrap().velocity = Vector2::new(0.0, 10.0);
+    world.tick(0.1);
+    assert!(world.get_body(standing).unwrap().contacts.is_empty());
+
+    // A crate stacked on another one stands on it
+    let bottom = world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(-7.0, 0.5), Vector2::new(1.0, 1.0))));
+    let top = world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(-7.0, 1.5), Vector2::new(1.0, 1.0))));
+    world.tick(0.1);
+
+    let contacts = &world.get_body(top).unwrap().contacts;
+    assert!(contacts.on_ground());
+    assert_eq!(contacts.get_ground().unwrap().target, ContactTarget::Body(bottom));
+
+    let contacts = &world.get_body(bottom).unwrap().contacts;
+    assert_eq!(contacts.get_ground().unwrap().target, ContactTarget::Static(floor));
+    assert_eq!(contacts.get_ceiling().unwrap().target, ContactTarget::Body(top));
+}
+
+
+pub fn filters() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+
+    let floor = world.add_static(Rectangle { min: Vector2::new(-10.0, -1.0), max: Vector2::new(10.0, 0.0) });
+    let platform = world.add_static(Rectangle { min: Vector2::new(-10.0, 3.0), max: Vector2::new(10.0, 3.5) });
+    world.set_static_one_way(platform, Some(Vector2::new(0.0, 1.0)));
+
+    // Jumps through the platform from below
+    let jumping = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(-5.0, 2.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(0.0, 20.0))
+    );
+
+    // Lands on the platform from above
+    let landing = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(-2.0, 4.5), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(0.0, -20.0))
+    );
+
+    // A projectile that passes through its shooter and the floor, but not other bodies
+    let shooter = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(2.0, 0.5), Vector2::new(1.0, 1.0)))
+            .with_filter(CollisionFilter::new(0b10, !0b100))
+    );
+    let projectile = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(2.0, 0.3), Vector2::new(0.2, 0.2)))
+            .with_velocity(Vector2::new(0.0, -10.0))
+            .with_filter(CollisionFilter::new(0b100, !0b11))
+    );
+
+    assert!(!CollisionFilter::default().collides_with(&CollisionFilter::new(0b100, !0b11)));
+    assert!(CollisionFilter::default().collides_with(&CollisionFilter::new(0b10, !0b100)));
+
+    // Only the landing body touches anything
+    let events = world.tick(0.1);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].body, landing);
+    assert_eq!(events[0].other, ContactTarget::Static(platform));
+
+    let body = world.get_body(jumping).unwrap();
+    assert_eq!(body.velocity, Vector2::new(0.0, 20.0));
+    assert_eq!(body.collider.center(), Vector2::new(-5.0, 4.0));
+    assert!(body.contacts.is_empty());
+
+    let body = world.get_body(landing).unwrap();
+    assert_eq!(body.velocity, Vector2::new(0.0, 0.0));
+    assert_eq!(body.collider.min.y, 3.5);
+    assert_eq!(body.contacts.get_ground().unwrap().target, ContactTarget::Static(platform));
+
+    let body = world.get_body(projectile).unwrap();
+    assert_eq!(body.velocity, Vector2::new(0.0, -10.0));
+    assert!(body.contacts.is_empty());
+
+    let body = world.get_body(shooter).unwrap();
+    assert_eq!(body.collider.center(), Vector2::new(2.0, 0.5));
+
+    // Once filtered, a static obstacle no longer blocks the default layer
+    world.set_static_filter(floor, CollisionFilter::new(1, 0b10));
+    world.get_body_mut(landing).unwrap().collider = Rectangle::centered(Vector2::new(-2.0, 1.0), Vector2::new(1.0, 1.0));
+    world.get_body_mut(landing).unwrap().velocity = Vector2::new(0.0, -10.0);
+    world.tick(0.1);
+
+    assert_eq!(world.get_body(landing).unwrap().collider.center(), Vector2::new(-2.0, 0.0));
+    assert_eq!(world.get_body(shooter).unwrap().collider.center(), Vector2::new(2.0, 0.5));
+}
+
+
+pub fn triggers() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+
+    let checkpoint = world.add_trigger(Rectangle { min: Vector2::new(2.0, 0.0), max: Vector2::new(3.0, 4.0) });
+
+    let runner = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.5), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(10.0, 0.0))
+    );
+
+    // Triggers never block bodies
+    assert!(world.tick(0.1).is_empty());
+    assert!(world.get_trigger_events().is_empty());
+
+    world.tick(0.1);
+    assert_eq!(world.get_body(runner).unwrap().collider.center(), Vector2::new(2.0, 0.5));
+
+    let events = world.get_trigger_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].trigger, events[0].body), (checkpoint, runner));
+    match events[0].phase {
+        TriggerPhase::Enter(overlap) => assert_eq!(overlap.depth, 0.5),
+        phase => panic!("Expected the runner to enter the checkpoint, got {:?}", phase)
+    }
+
+    world.tick(0.1);
+    let events = world.get_trigger_events();
+    assert_eq!(events.len(), 1);
+    match events[0].phase {
+        TriggerPhase::Stay(overlap) => assert_eq!(overlap.resolve, Vector2::new(0.5, 0.0)),
+        phase => panic!("Expected the runner to stay in the checkpoint, got {:?}", phase)
+    }
+
+    world.tick(0.1);
+    let events = world.get_trigger_events();
+    assert_eq!(events.len(), 1);
+    match events[0].phase {
+        TriggerPhase::Exit => {}
+        phase => panic!("Expected the runner to exit the checkpoint, got {:?}", phase)
+    }
+
+    world.tick(0.1);
+    assert!(world.get_trigger_events().is_empty());
+
+    // Removing a body inside a trigger exits it
+    let pickup = world.add_trigger(Rectangle { min: Vector2::new(-1.0, -1.0), max: Vector2::new(1.0, 1.0) });
+    let collector = world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0))));
+
+    world.tick(0.1);
+    assert_eq!(world.get_trigger_events().len(), 1);
+
+    world.remove_body(collector);
+    world.tick(0.1);
+
+    let events = world.get_trigger_events();
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].trigger, events[0].body), (pickup, collector));
+    match events[0].phase {
+        TriggerPhase::Exit => {}
+        phase => panic!("Expected the collector to exit the pickup, got {:?}", phase)
+    }
+}
+
+
+pub fn kinematics() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    world.set_gravity(Vector2::new(0.0, -20.0));
+
+    // A platform moving sideways with a rider on top
+    let platform = world.add_kinematic(
+        Rectangle { min: Vector2::new(-2.0, -1.0), max: Vector2::new(2.0, 0.0) },
+        Vector2::new(5.0, 0.0)
+    );
+
+    let rider = world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.5), Vector2::new(1.0, 1.0))));
+
+    // An elevator moving up with a passenger on top
+    let elevator = world.add_kinematic(
+        Rectangle { min: Vector2::new(20.0, -1.0), max: Vector2::new(22.0, 0.0) },
+        Vector2::new(0.0, 2.0)
+    );
+
+    let passenger = world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(21.0, 0.5), Vector2::new(1.0, 1.0))));
+
+    // A wall moving towards a floating box
+    world.add_kinematic(
+        Rectangle { min: Vector2::new(-20.0, 10.0), max: Vector2::new(-19.0, 12.0) },
+        Vector2::new(10.0, 0.0)
+    );
+
+    let floating = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(-17.5, 11.0), Vector2::new(1.0, 1.0)))
+            .with_gravity_scale(0.0)
+    );
+
+    for _ in 0..10 {
+        world.tick(0.1);
+    }
+
+    assert_eq!(world.get_static(platform).unwrap().center(), Vector2::new(5.0, -0.5));
+    assert_eq!(world.get_static_velocity(platform), Some(Vector2::new(5.0, 0.0)));
+
+    // The rider moved along with the platform without sliding off,
+    // from the second step on once it was standing on it
+    let body = world.get_body(rider).unwrap();
+    assert!(approx(body.collider.center().x, 4.5));
+    assert!(approx(body.collider.min.y, 0.0));
+    assert_eq!(body.contacts.get_ground().unwrap().target, ContactTarget::Static(platform));
+    assert!(approx(body.velocity.x, 0.0));
+
+    // The passenger was lifted up with the elevator
+    assert!(approx(world.get_static(elevator).unwrap().max.y, 2.0));
+    let body = world.get_body(passenger).unwrap();
+    assert!(approx(body.collider.min.y, 2.0));
+    assert_eq!(body.contacts.get_ground().unwrap().target, ContactTarget::Static(elevator));
+
+    // The wall pushed the box in front of it
+    let body = world.get_body(floating).unwrap();
+    assert!(approx(body.collider.min.x, -9.0));
+    assert!(approx(body.velocity.x, 10.0));
+
+    // Stopped platforms no longer carry their riders
+    world.set_kinematic_velocity(platform, Vector2::zero());
+    world.tick(0.1);
+    assert!(approx(world.get_body(rider).unwrap().collider.center().x, 4.5));
+
+    // Riders with drag keep up with the platform, and walls stop them without pushing them back
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    world.set_gravity(Vector2::new(0.0, -20.0));
+
+    world.add_kinematic(Rectangle { min: Vector2::new(-6.0, -1.0), max: Vector2::new(6.0, 0.0) }, Vector2::new(5.0, 0.0));
+    let wall = world.add_static(Rectangle { min: Vector2::new(3.0, 0.5), max: Vector2::new(4.0, 3.0) });
+
+    let rider = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.5), Vector2::new(1.0, 1.0)))
+            .with_drag(Vector2::new(2.0, 2.0))
+    );
+
+    for _ in 0..4 {
+        world.tick(0.1);
+    }
+
+    assert!(approx(world.get_body(rider).unwrap().collider.center().x, 1.5));
+
+    for _ in 0..6 {
+        world.tick(0.1);
+    }
+
+    let body = world.get_body(rider).unwrap();
+    assert!(approx(body.collider.max.x, 3.0));
+    assert!(body.contacts.iter().any(|contact| contact.target == ContactTarget::Static(wall)));
+    assert_eq!(body.velocity.x, 0.0);
+}
+
+
+pub fn fast_bodies() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+
+    // Far too fast to ever overlap the enemy at the end of a step
+    let bullet = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.0), Vector2::new(0.2, 0.2)))
+            .with_velocity(Vector2::new(100.0, 0.0))
+            .with_mass(0.1)
+    );
+
+    let enemy = world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(5.0, 0.0), Vector2::new(1.0, 1.0))));
+
+    // Once they hit, they move together and touch at the end of the step
+    let events = world.tick(0.1);
+    assert!(events.len() >= 2);
+    assert_eq!(events[0].body, bullet);
+    assert_eq!(events[0].other, ContactTarget::Body(enemy));
+    assert_eq!(events[0].normal, Vector2::new(-1.0, 0.0));
+    assert_eq!(events[1].normal, Vector2::new(1.0, 0.0));
+
+    // The bullet hit the enemy instead of passing through it
+    assert!(world.get_body(bullet).unwrap().velocity.x < 100.0);
+    assert!(world.get_body(enemy).unwrap().velocity.x > 0.0);
+    let (a, b) = (world.get_body(bullet).unwrap(), world.get_body(enemy).unwrap());
+    assert!((a.collider.max.x - b.collider.min.x).abs() < 1e-9);
+    assert!((a.velocity.x - b.velocity.x).abs() < 1e-9);
+
+    // A wall between two bodies racing towards each other stops both before they meet
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+    world.add_static(Rectangle { min: Vector2::new(4.5, -5.0), max: Vector2::new(5.5, 5.0) });
+
+    let left = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(100.0, 0.0))
+    );
+
+    let right = world.add_body(
+        RigidBody::new(Rectangle::centered(Vector2::new(10.0, 0.0), Vector2::new(1.0, 1.0)))
+            .with_velocity(Vector2::new(-100.0, 0.0))
+    );
+
+    let events = world.tick(0.1);
+    assert!(events.iter().all(|event| event.other == ContactTarget::Static(0)));
+
+    let (a, b) = (world.get_body(left).unwrap(), world.get_body(right).unwrap());
+    assert!((a.collider.max.x - 4.5).abs() < 1e-9);
+    assert!((b.collider.min.x - 5.5).abs() < 1e-9);
+
+    // Both moving towards each other
+    let impact = Rectangle::centered(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)).sweep_moving(
+        Vector2::new(4.0, 0.0),
+        &Rectangle::centered(Vector2::new(5.0, 0.0), Vector2::new(1.0, 1.0)),
+        Vector2::new(-4.0, 0.0)
+    ).unwrap();
+
+    assert_eq!(impact.time, 0.5);
+    assert_eq!(impact.normal, Vector2::new(-1.0, 0.0));
+
+    // Moving in the same direction at the same speed never hits
+    assert!(Rectangle::centered(Vector2::new(0.0, 0.0), Vector2::new(1.0, 1.0)).sweep_moving(
+        Vector2::new(4.0, 0.0),
+        &Rectangle::centered(Vector2::new(2.0, 0.0), Vector2::new(1.0, 1.0)),
+        Vector2::new(4.0, 0.0)
+    ).is_none());
+}
+
+
+pub fn shape_cast() {
+    let mut world = PhysicsWorld::new(Vector2::new(4.0, 4.0));
+
+    world.add_static(Rectangle { min: Vector2::new(-10.0, -1.0), max: Vector2::new(10.0, 0.0) });
+    let near_wall = world.add_static(Rectangle { min: Vector2::new(3.0, 0.0), max: Vector2::new(4.0, 5.0) });
+    world.add_static(Rectangle { min: Vector2::new(6.0, 0.0), max: Vector2::new(7.0, 5.0) });
+
+    let collider = Rectangle::centered(Vector2::new(0.0, 0.5), Vector2::new(1.0, 1.0));
+
+    // Walking right hits the nearest wall, sliding along the floor doesn't count
+    let (handle, impact) = world.cast_shape(&collider, Vector2::new(10.0, 0.0)).unwrap();
+    assert_eq!(handle, near_wall);
+    assert_eq!(impact.time, 0.25);
+    assert_eq!(impact.normal, Vector2::new(-1.0, 0.0));
+
+    // Walking left is possible, nothing changed in the world
+    assert!(world.cast_shape(&collider, Vector2::new(-5.0, 0.0)).is_none());
+    assert!(world.get_body_handles().is_empty());
+
+    // Bodies are not obstacles for casts
+    world.add_body(RigidBody::new(Rectangle::centered(Vector2::new(-2.0, 0.5), Vector2::new(1.0, 1.0))));
+    assert!(world.cast_shape(&collider, Vector2::new(-5.0, 0.0)).is_none());
+
+    // Casts only hit the obstacles their filter collides with
+    world.set_static_filter(near_wall, CollisionFilter::new(2, !0));
+    let (handle, impact) = world.cast_shape_filtered(&collider, Vector2::new(10.0, 0.0), CollisionFilter::new(1, 1)).unwrap();
+    assert_eq!(handle, near_wall + 1);
+    assert!((impact.time - 0.55).abs() < 1e-9);
+    assert_eq!(world.cast_shape(&collider, Vector2::new(10.0, 0.0)).unwrap().0, near_wall);
+
+    // One-way obstacles only block casts moving against them
+    world.set_static_one_way(near_wall, Some(Vector2::new(1.0, 0.0)));
+    assert_eq!(world.cast_shape(&collider, Vector2::new(10.0, 0.0)).unwrap().0, near_wall + 1);
+
+    // Casting against any collection of obstacles
+    let obstacles = vec![
+        Rectangle { min: Vector2::new(-1.0, 4.0), max: Vector2::new(1.0, 5.0) },
+        Rectangle { min: Vector2::new(-1.0, 2.0), max: Vector2::new(1.0, 3.0) },
+    ];
+
+    let (index, impact) = cast_shape(&collider, Vector2::new(0.0, 4.0), &obstacles).unwrap();
+    assert_eq!(index, 1);
+    assert_eq!(impact.time, 0.25);
+    assert_eq!(impact.normal, Vector2::new(0.0, -1.0));
+}
+
+
+/// Return true if two numbers are almost the same
+fn approx(a: f64, b: f64) -> bool {
+    (a - b).abs() < 0.05
+}
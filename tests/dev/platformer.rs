@@ -7,9 +7,6 @@ const TILE_SIZE: f64 = 128.0;
 const WORLD_SIZE: [usize; 2] = [128, 128];
 const MOVEMENT_SPEED: f64 = 30.0;
 
-
-use super::frame_counter::FrameCounter;
-
 use crank;
 
 use crank::{WindowHandle, UpdateInfo, Renderer};
@@ -20,8 +17,8 @@ use crank::Image;
 
 use crank::KeyCode;
 
-use crank::{Collide};
-use crank::{PhysicsObject, Body};
+use crank::{Collide, SpatialGrid};
+use crank::{PhysicsObject, Obstacle};
 
 use crank::{Vector2};
 
@@ -34,6 +31,7 @@ pub fn main() {
     let settings = crank::GameSettings {
         vertical_sync: false,
         clear_color: [0.2; 4],
+        fixed_update: Some(crank::FixedUpdate { tick_rate: 1024.0, max_steps: 1024 }),
     };
 
     crank::run_game::<Platformer>(800, 600, "Platformer", settings).unwrap();
@@ -43,9 +41,6 @@ pub fn main() {
 pub struct Platformer {
     running: bool,
     window: WindowHandle,
-    frame_counter: FrameCounter,
-
-    time_accumulator: f64,
 
     batch: RenderBatch,
     view: Rectangle,
@@ -66,25 +61,20 @@ impl Platformer {
 
         if direction.x != 0.0 || direction.y != 0.0 {
             direction = direction.normal();
-            let delta = MOVEMENT_SPEED * dt * direction;
-            self.player.apply_force(delta.into());
+            self.player.apply_force(MOVEMENT_SPEED * direction, dt);
         }
 
 
-        let velocity = self.player.get_velocity();
-
-        let bounds = Rectangle::centered(
-            self.player.get_position(),
-            velocity.abs() + Vector2::new(10.0, 10.0)
-        );
+        self.player.update(dt);
 
-        let world_obstacles = self.world.get_obstacles(bounds);
-        let mut obstacles: Vec<Box<&Body<<Player as PhysicsObject>::CollisionBody>>> = Vec::new();
+        // Only the tiles the player can reach this tick
+        let path = self.player.get_velocity() * dt;
+        let world_obstacles = self.world.get_obstacles(self.player.get_collider(), path);
+        let mut obstacles: Vec<Obstacle<<Player as PhysicsObject>::CollisionBody>> = Vec::new();
         for rect in world_obstacles.iter() {
-            obstacles.push(Box::new(rect));
+            obstacles.push(Obstacle::new(rect));
         }
 
-        self.player.update(dt);
         self.player.tick(dt, obstacles.as_slice());
 
 
@@ -172,9 +162,6 @@ impl crank::Game for Platformer {
             running: true,
             window,
 
-            frame_counter: FrameCounter::new(),
-            time_accumulator: 0.0,
-
             batch: RenderBatch::new(),
 
             view: Rectangle::default(),
@@ -186,21 +173,13 @@ impl crank::Game for Platformer {
         platformer
     }
 
-    fn update(&mut self, info: UpdateInfo) {
-        const UPDATE_RATE: f64 = 1.0 / 1024.0;
-
-        self.time_accumulator += info.dt;
-        if self.time_accumulator > 1.0 {
-            self.time_accumulator = 0.0;
-        }
-
-        while self.time_accumulator > UPDATE_RATE {
-            self.tick(UPDATE_RATE);
-            self.time_accumulator -= UPDATE_RATE;
-        }
+    fn fixed_update(&mut self, info: UpdateInfo) {
+        self.tick(info.dt);
+    }
 
-        if let Some(fps) = self.frame_counter.tick() {
-            // self.window.set_title(&format!("FPS: {}   ---   Tiles: {}", fps, WORLD_SIZE[0] * WORLD_SIZE[1]));
+    fn update(&mut self, info: UpdateInfo) {
+        if info.stats.frame % 30 == 0 {
+            // self.window.set_title(&format!("FPS: {}   ---   Tiles: {}", info.stats.fps.round(), WORLD_SIZE[0] * WORLD_SIZE[1]));
         }
 
         self.draw();
@@ -252,6 +231,9 @@ const CHUNK_SIZE: [usize; 2] = [32; 2];
 struct World {
     chunk_indices: HashMap<[i32; 2], usize>,
     chunks: Vec<(Rectangle, Chunk)>,
+
+    obstacles: SpatialGrid<Rectangle>,
+    obstacle_handles: HashMap<[i32; 2], usize>,
 }
 
 
@@ -272,7 +254,10 @@ impl World {
     pub fn from_indices(width: usize, height: usize, tile_indices: Vec<usize>, dictionary: &Vec<Tile>) -> World {
         let mut world = World {
             chunk_indices: HashMap::new(),
-            chunks: Vec::new()
+            chunks: Vec::new(),
+
+            obstacles: SpatialGrid::new([CHUNK_SIZE[0] as f64 / 4.0, CHUNK_SIZE[1] as f64 / 4.0].into()),
+            obstacle_handles: HashMap::new(),
         };
 
         let chunks_x = (width as f64 / CHUNK_SIZE[0] as f64).ceil() as usize;
@@ -365,16 +350,11 @@ impl World {
         batch.set_texture(None);
     }
 
-    pub fn get_obstacles(&self, bounds: Rectangle) -> Vec<Rectangle> {
-        let mut obstacles = Vec::new();
-
-        for &(rect, ref chunk) in self.chunks.iter() {
-            if bounds.intersects(&rect) {
-                obstacles.extend(chunk.get_obstacles(bounds));
-            }
-        }
-
-        obstacles
+    pub fn get_obstacles(&self, collider: &Rectangle, path: Vector2) -> Vec<Rectangle> {
+        // Everything the collider could reach, including by sliding along what it hits first
+        self.obstacles.query_region(&collider.swept_region(path)).into_iter()
+            .filter_map(|handle| self.obstacles.get(handle).cloned())
+            .collect()
     }
 
 
@@ -388,6 +368,16 @@ impl World {
 
             chunk.set_tile(x, y, tile);
         }
+
+        // Replace the collider of the previous tile
+        if let Some(handle) = self.obstacle_handles.remove(&[x, y]) {
+            self.obstacles.remove(handle);
+        }
+
+        if tile.is_solid() {
+            let handle = self.obstacles.insert(Rectangle::centered([x as f64, y as f64].into(), [1.0; 2].into()));
+            self.obstacle_handles.insert([x, y], handle);
+        }
     }
 }
 
@@ -426,20 +416,6 @@ impl Chunk {
         }
     }
 
-
-    pub fn get_obstacles(&self, bounds: Rectangle) -> Vec<Rectangle> {
-        let mut obstacles = Vec::new();
-
-        for (_, tile) in self.tiles.iter() {
-            if tile.1.is_solid() {
-                if bounds.intersects(&tile.0) {
-                    obstacles.push(tile.0.clone());
-                }
-            }
-        }
-
-        obstacles
-    }
 }
 
 
@@ -0,0 +1,379 @@
+use crank::{Collide, RayCast, Sweep, Bounded, PhysicsObject, Obstacle, cast_ray, PointQuery, Distance};
+use crank::{Circle, Rectangle, OrientedRectangle, Polygon, Triangle, Line, Vector2, RenderShape, Renderer};
+
+
+pub fn circle() {
+    let circle = Circle::new(Vector2::new(0.0, 0.0), 1.0);
+
+    assert!(circle.contains(Vector2::new(0.5, 0.5)));
+    assert!(!circle.contains(Vector2::new(1.0, 1.0)));
+    assert!(circle.bounding_box() == Rectangle::centered(Vector2::zero(), Vector2::new(2.0, 2.0)));
+
+    // Against other circles
+    let other = Circle::new(Vector2::new(1.5, 0.0), 1.0);
+    assert!(circle.intersects(&other));
+
+    let overlap = circle.overlap(&other).unwrap();
+    assert_eq!(overlap.depth, 0.5);
+    assert_eq!(overlap.resolve, Vector2::new(-0.5, 0.0));
+
+    assert!(!circle.intersects(&Circle::new(Vector2::new(3.0, 0.0), 1.0)));
+
+    // Against rectangles
+    let rect = Rectangle { min: Vector2::new(0.5, -4.0), max: Vector2::new(4.0, 4.0) };
+    assert!(circle.intersects(&rect));
+    assert_eq!(circle.overlap(&rect).unwrap().resolve, Vector2::new(-0.5, 0.0));
+    assert_eq!(rect.overlap(&circle).unwrap().resolve, Vector2::new(0.5, 0.0));
+
+    // Only the corner of the rectangle is close to the circle
+    let corner = Rectangle { min: Vector2::new(0.8, 0.8), max: Vector2::new(2.0, 2.0) };
+    assert!(!circle.intersects(&corner));
+    assert!(circle.overlap(&corner).is_none());
+
+    // Ray casts
+    let intersection = circle.ray_intersection(Vector2::new(-3.0, 0.0), Vector2::new(1.0, 0.0)).unwrap();
+    assert_eq!(intersection.time, 2.0);
+    assert_eq!(intersection.point, Vector2::new(-1.0, 0.0));
+    assert_eq!(intersection.normal, Vector2::new(-1.0, 0.0));
+    assert!(circle.ray_intersection(Vector2::new(-3.0, 2.0), Vector2::new(1.0, 0.0)).is_none());
+
+    // Sweeps
+    let impact = circle.sweep(Vector2::new(4.0, 0.0), &Circle::new(Vector2::new(4.0, 0.0), 1.0)).unwrap();
+    assert_eq!(impact.time, 0.5);
+    assert_eq!(impact.normal, Vector2::new(-1.0, 0.0));
+
+    let floor = Rectangle { min: Vector2::new(-4.0, -4.0), max: Vector2::new(4.0, -2.0) };
+    let impact = circle.sweep(Vector2::new(0.0, -2.0), &floor).unwrap();
+    assert_eq!(impact.time, 0.5);
+    assert_eq!(impact.normal, Vector2::new(0.0, 1.0));
+
+    let impact = floor.sweep(Vector2::new(0.0, 2.0), &circle).unwrap();
+    assert_eq!(impact.time, 0.5);
+    assert_eq!(impact.normal, Vector2::new(0.0, -1.0));
+
+    // Sweeping past the rounded corner of the rectangle
+    let corner = Rectangle { min: Vector2::new(1.9, 1.9), max: Vector2::new(4.0, 4.0) };
+    assert!(circle.sweep(Vector2::new(4.0, 0.0), &corner).is_none());
+}
+
+
+pub fn polygon() {
+    // A slope rising to the right, given in clockwise order
+    let slope = Polygon::from(Triangle::new(
+        Vector2::new(0.0, 0.0),
+        Vector2::new(4.0, 4.0),
+        Vector2::new(4.0, 0.0),
+    ));
+
+    assert_eq!(slope.points, vec![Vector2::new(4.0, 0.0), Vector2::new(4.0, 4.0), Vector2::new(0.0, 0.0)]);
+    assert!(slope.contains(Vector2::new(3.0, 1.0)));
+    assert!(!slope.contains(Vector2::new(1.0, 3.0)));
+    assert!(slope.bounding_box() == Rectangle { min: Vector2::zero(), max: Vector2::new(4.0, 4.0) });
+
+    let hull = Polygon::convex_hull(&[
+        Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.5, 0.5),
+        Vector2::new(1.0, 1.0), Vector2::new(0.0, 1.0),
+    ]);
+    assert_eq!(hull.points.len(), 4);
+    assert!(hull == Polygon::from(Rectangle { min: Vector2::zero(), max: Vector2::new(1.0, 1.0) }));
+
+    // A box whose corner is pushed into the slope is moved out perpendicular to it
+    let corner = Rectangle { min: Vector2::new(1.0, 1.5), max: Vector2::new(2.0, 2.5) };
+    assert!(slope.intersects(&corner));
+
+    let overlap = corner.overlap(&slope).unwrap();
+    assert!(approx(overlap.depth, 0.5 / 2.0_f64.sqrt()));
+    assert!(approx(overlap.resolve.x, -0.25) && approx(overlap.resolve.y, 0.25));
+
+    let above = Rectangle { min: Vector2::new(1.0, 2.5), max: Vector2::new(2.0, 3.5) };
+    assert!(!slope.intersects(&above));
+    assert!(slope.overlap(&above).is_none());
+
+    // Ray casts
+    let intersection = slope.ray_intersection(Vector2::new(2.0, 5.0), Vector2::new(0.0, -1.0)).unwrap();
+    assert!(approx(intersection.time, 3.0));
+    assert!(approx(intersection.point.y, 2.0));
+    assert!(approx(intersection.normal.x, -0.5_f64.sqrt()) && approx(intersection.normal.y, 0.5_f64.sqrt()));
+    assert!(slope.ray_intersection(Vector2::new(5.0, 5.0), Vector2::new(0.0, 1.0)).is_none());
+
+    // Sweeps
+    let impact = above.sweep(Vector2::new(0.0, -4.0), &slope).unwrap();
+    assert!(approx(impact.time, 0.125));
+    assert!(approx(impact.normal.x, -0.5_f64.sqrt()) && approx(impact.normal.y, 0.5_f64.sqrt()));
+
+    let square = Polygon::from(Rectangle { min: Vector2::new(-6.0, 2.0), max: Vector2::new(-5.0, 3.0) });
+    let floor = Rectangle { min: Vector2::new(-8.0, -1.0), max: Vector2::new(-4.0, 0.0) };
+
+    let impact = square.sweep(Vector2::new(0.0, -4.0), &floor).unwrap();
+    assert!(approx(impact.time, 0.5));
+    assert_eq!(impact.normal, Vector2::new(0.0, 1.0));
+
+    assert!(square.sweep(Vector2::new(4.0, 0.0), &slope).is_none());
+
+    // Flat polygons are drawn without dividing by their zero height
+    let _software_textures = crank::SoftwareTextureScope::new();
+    let mut batch = crank::RenderBatch::new();
+    batch.fill_polygon(&Polygon { points: vec![Vector2::new(0.0, 1.0), Vector2::new(1.0, 1.0), Vector2::new(2.0, 1.0)] });
+
+    let mut renderer = crank::RecordingRenderer::new();
+    renderer.submit_batch(&batch);
+
+    match renderer.get_commands()[0] {
+        crank::RenderCommand::SubmitBatch(ref recorded) => {
+            let tex_coords: Vec<[f32; 2]> = recorded.meshes[0].vertices.iter().map(|vertex| vertex.tex_coord).collect();
+            assert_eq!(tex_coords, vec![[0.0, 1.0], [0.5, 1.0], [1.0, 1.0]]);
+        }
+
+        ref command => panic!("Expected a batch, got {:?}", command)
+    }
+}
+
+
+pub fn line_and_triangle() {
+    let wall = Line::new(Vector2::new(0.0, -1.0), Vector2::new(0.0, 1.0));
+
+    let intersection = wall.ray_intersection(Vector2::new(-2.0, 0.0), Vector2::new(1.0, 0.0)).unwrap();
+    assert_eq!(intersection.time, 2.0);
+    assert_eq!(intersection.point, Vector2::new(0.0, 0.0));
+    assert_eq!(intersection.normal, Vector2::new(-1.0, 0.0));
+
+    assert!(wall.ray_intersection(Vector2::new(-2.0, 2.0), Vector2::new(1.0, 0.0)).is_none());
+    assert!(wall.ray_intersection(Vector2::new(-2.0, 0.0), Vector2::new(0.0, 1.0)).is_none());
+
+    // Line of sight
+    assert!(wall.line_intersection(&Line::new(Vector2::new(-2.0, 0.0), Vector2::new(2.0, 0.5))).is_some());
+    assert!(wall.line_intersection(&Line::new(Vector2::new(-2.0, 0.0), Vector2::new(-1.0, 0.0))).is_none());
+
+    let rect = Rectangle::centered(Vector2::zero(), Vector2::new(1.0, 1.0));
+    assert!(Line::new(Vector2::new(-1.0, -1.0), Vector2::new(1.0, 1.0)).intersects(&rect));
+    assert!(!rect.intersects(&Line::new(Vector2::new(2.0, 0.0), Vector2::new(3.0, 1.0))));
+
+    // Triangle terrain
+    let triangle = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+
+    let intersection = triangle.ray_intersection(Vector2::new(-1.0, 1.0), Vector2::new(1.0, 0.0)).unwrap();
+    assert_eq!(intersection.time, 1.0);
+    assert_eq!(intersection.normal, Vector2::new(-1.0, 0.0));
+
+    let sunken = Rectangle { min: Vector2::new(1.0, -0.5), max: Vector2::new(2.0, 0.5) };
+    assert!(triangle.intersects(&sunken));
+
+    let overlap = sunken.overlap(&triangle).unwrap();
+    assert_eq!(overlap.depth, 0.5);
+    assert_eq!(overlap.resolve, Vector2::new(0.0, -0.5));
+
+    assert!(triangle.overlap(&Rectangle { min: Vector2::new(3.0, 3.0), max: Vector2::new(4.0, 4.0) }).is_none());
+}
+
+
+fn approx(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+
+pub fn ray_hits() {
+    let origin = Vector2::new(-5.0, 0.5);
+    let direction = Vector2::new(1.0, 0.0);
+
+    // Enters through the left side and exits through the right side
+    let rect = Rectangle { min: Vector2::new(-1.0, 0.0), max: Vector2::new(1.0, 2.0) };
+    let hit = rect.ray_hit(origin, direction).unwrap();
+    assert_eq!((hit.entry.time, hit.entry.normal), (4.0, Vector2::new(-1.0, 0.0)));
+    assert_eq!((hit.exit.time, hit.exit.normal), (6.0, Vector2::new(1.0, 0.0)));
+    assert_eq!(hit.exit.point, Vector2::new(1.0, 0.5));
+
+    let circle = Circle::new(Vector2::new(0.0, 0.5), 1.0);
+    let hit = circle.ray_hit(origin, direction).unwrap();
+    assert!(approx(hit.entry.time, 4.0) && approx(hit.exit.time, 6.0));
+    assert_eq!(hit.exit.normal, Vector2::new(1.0, 0.0));
+
+    let triangle = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(2.0, 0.0), Vector2::new(0.0, 2.0));
+    let hit = triangle.ray_hit(origin, direction).unwrap();
+    assert!(approx(hit.entry.time, 5.0) && approx(hit.exit.time, 6.5));
+    assert_eq!(hit.entry.normal, Vector2::new(-1.0, 0.0));
+    assert!(approx(hit.exit.normal.x, 0.5f64.sqrt()) && approx(hit.exit.normal.y, 0.5f64.sqrt()));
+
+    // Lines are entered and exited at the same point
+    let line = Line::new(Vector2::new(2.0, -1.0), Vector2::new(2.0, 1.0));
+    let hit = line.ray_hit(origin, direction).unwrap();
+    assert_eq!(hit.entry.time, hit.exit.time);
+    assert_eq!(hit.entry.normal, -hit.exit.normal);
+
+    // Pierce through a row of walls, skipping the one behind the ray and the one it misses
+    let walls = vec![
+        Rectangle { min: Vector2::new(6.0, 0.0), max: Vector2::new(7.0, 1.0) },
+        Rectangle { min: Vector2::new(2.0, 0.0), max: Vector2::new(3.0, 1.0) },
+        Rectangle { min: Vector2::new(-8.0, 0.0), max: Vector2::new(-7.0, 1.0) },
+        Rectangle { min: Vector2::new(4.0, 3.0), max: Vector2::new(5.0, 4.0) },
+        Rectangle { min: Vector2::new(-6.0, 0.0), max: Vector2::new(-4.0, 1.0) },
+    ];
+
+    let hits = cast_ray(&walls, origin, direction);
+    let indices: Vec<usize> = hits.iter().map(|&(index, _)| index).collect();
+    assert_eq!(indices, vec![4, 1, 0]);
+
+    // The ray starts inside the first wall
+    assert_eq!(hits[0].1.entry.time, -1.0);
+    assert_eq!(hits[0].1.exit.time, 1.0);
+    assert_eq!(hits[2].1.entry.point, Vector2::new(6.0, 0.5));
+}
+
+
+pub fn point_queries() {
+    let rect = Rectangle { min: Vector2::new(0.0, 0.0), max: Vector2::new(4.0, 2.0) };
+
+    assert!(rect.contains_point(Vector2::new(1.0, 1.0)));
+    assert_eq!(rect.closest_point(Vector2::new(6.0, 5.0)), Vector2::new(4.0, 2.0));
+    assert_eq!(rect.closest_point(Vector2::new(1.0, 1.5)), Vector2::new(1.0, 2.0));
+    assert_eq!(rect.signed_distance(Vector2::new(7.0, 6.0)), 5.0);
+    assert_eq!(rect.signed_distance(Vector2::new(3.5, 1.0)), -0.5);
+
+    // Lines only contain the points along them
+    let line = Line::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 4.0));
+    assert!(line.contains_point(Vector2::new(1.0, 1.0)));
+    assert!(!line.contains_point(Vector2::new(1.0, 2.0)));
+    assert!(Line::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 3.0)).contains_point(Vector2::new(0.1, 0.3)));
+    assert!(!Line::new(Vector2::new(0.0, 0.0), Vector2::new(1.0, 3.0)).contains_point(Vector2::new(0.1, 0.31)));
+    assert_eq!(line.closest_point(Vector2::new(0.0, 2.0)), Vector2::new(1.0, 1.0));
+    assert_eq!(line.closest_point(Vector2::new(6.0, 5.0)), Vector2::new(4.0, 4.0));
+    assert_eq!(line.signed_distance(Vector2::new(-3.0, -4.0)), 5.0);
+
+    let triangle = Triangle::new(Vector2::new(0.0, 0.0), Vector2::new(4.0, 0.0), Vector2::new(0.0, 4.0));
+    assert!(triangle.contains_point(Vector2::new(1.0, 1.0)));
+    assert!(!triangle.contains_point(Vector2::new(3.0, 3.0)));
+    assert_eq!(triangle.closest_point(Vector2::new(3.0, 3.0)), Vector2::new(2.0, 2.0));
+    assert_eq!(triangle.signed_distance(Vector2::new(1.0, 1.0)), -1.0);
+
+    let circle = Circle::new(Vector2::new(0.0, 0.0), 1.0);
+    assert_eq!(circle.closest_point(Vector2::new(0.0, -3.0)), Vector2::new(0.0, -1.0));
+    assert_eq!(circle.signed_distance(Vector2::new(0.0, 0.5)), -0.5);
+
+    // Distances between separated shapes
+    let other = Rectangle { min: Vector2::new(7.0, 6.0), max: Vector2::new(8.0, 7.0) };
+    assert_eq!(rect.distance(&other), 5.0);
+    assert_eq!(other.distance(&rect), 5.0);
+    assert_eq!(rect.distance(&Line::new(Vector2::new(6.0, 0.0), Vector2::new(6.0, 4.0))), 2.0);
+    assert!(approx(line.distance(&Line::new(Vector2::new(4.0, 0.0), Vector2::new(6.0, 0.0))), 8.0f64.sqrt()));
+    assert!(approx(triangle.distance(&other), 4.5 * 2.0f64.sqrt()));
+    assert_eq!(Circle::new(Vector2::new(0.0, 5.0), 1.0).distance(&rect), 2.0);
+
+    // Overlapping shapes have the negative depth of the overlap
+    let overlapping = Rectangle { min: Vector2::new(3.0, 1.0), max: Vector2::new(5.0, 3.0) };
+    assert_eq!(rect.distance(&overlapping), -1.0);
+    assert_eq!(triangle.distance(&Rectangle { min: Vector2::new(-1.0, -1.0), max: Vector2::new(1.0, 0.5) }), -0.5);
+    assert_eq!(circle.distance(&Circle::new(Vector2::new(1.5, 0.0), 1.0)), -0.5);
+
+    // Lines have no inside, so crossing lines touch
+    assert_eq!(line.distance(&Line::new(Vector2::new(0.0, 4.0), Vector2::new(4.0, 0.0))), 0.0);
+}
+
+
+pub fn oriented_rectangle() {
+    use std::f64::consts::PI;
+
+    // A square standing on one of its corners
+    let diamond = Rectangle::centered(Vector2::zero(), Vector2::new(2.0, 2.0)).rotated(PI / 4.0);
+    assert_eq!(diamond, OrientedRectangle::new(Vector2::zero(), Vector2::new(1.0, 1.0), PI / 4.0));
+
+    let corners = diamond.corners();
+    assert!(approx(corners[0].x, 0.0) && approx(corners[0].y, -2.0f64.sqrt()));
+    assert!(approx(corners[1].x, 2.0f64.sqrt()) && approx(corners[1].y, 0.0));
+
+    assert!(diamond.contains(Vector2::new(0.0, 1.3)));
+    assert!(!diamond.contains(Vector2::new(0.9, 0.9)));
+
+    let bounds = diamond.bounding_box();
+    assert!(approx(bounds.max.x, 2.0f64.sqrt()) && approx(bounds.min.y, -2.0f64.sqrt()));
+
+    // The corner pokes into a box that the unrotated square wouldn't touch
+    let rect = Rectangle { min: Vector2::new(1.2, -0.1), max: Vector2::new(2.0, 0.1) };
+    assert!(diamond.intersects(&rect));
+    assert!(rect.intersects(&diamond));
+    assert!(!Rectangle::centered(Vector2::zero(), Vector2::new(2.0, 2.0)).intersects(&rect));
+
+    let overlap = rect.overlap(&diamond).unwrap();
+    assert!(overlap.resolve.x > 0.0);
+
+    let other = OrientedRectangle::new(Vector2::new(2.5, 0.0), Vector2::new(1.0, 1.0), PI / 4.0);
+    assert!(diamond.intersects(&other));
+    assert!(!diamond.intersects(&other.rotated(PI / 4.0)));
+
+    // Rays hit the slanted sides
+    let hit = diamond.ray_hit(Vector2::new(-5.0, 0.5), Vector2::new(1.0, 0.0)).unwrap();
+    assert!(approx(hit.entry.time, 5.0 - (2.0f64.sqrt() - 0.5)));
+    assert!(approx(hit.entry.normal.x, -0.5f64.sqrt()) && approx(hit.entry.normal.y, 0.5f64.sqrt()));
+    assert!(approx(hit.exit.time, 5.0 + (2.0f64.sqrt() - 0.5)));
+
+    // Negative half extents describe the same rectangle
+    let flipped = OrientedRectangle { half_extents: Vector2::new(-1.0, 1.0), ..diamond };
+    let hit = flipped.ray_hit(Vector2::new(-5.0, 0.5), Vector2::new(1.0, 0.0)).unwrap();
+    assert!(approx(hit.entry.time, 5.0 - (2.0f64.sqrt() - 0.5)));
+
+    // Sweeping into an axis aligned wall and back
+    let wall = Rectangle { min: Vector2::new(3.0, -1.0), max: Vector2::new(4.0, 1.0) };
+    let impact = diamond.sweep(Vector2::new(4.0, 0.0), &wall).unwrap();
+    assert!(approx(impact.time, (3.0 - 2.0f64.sqrt()) / 4.0));
+    assert_eq!(impact.normal, Vector2::new(-1.0, 0.0));
+
+    let impact = wall.sweep(Vector2::new(-4.0, 0.0), &diamond).unwrap();
+    assert!(approx(impact.time, (3.0 - 2.0f64.sqrt()) / 4.0));
+    assert_eq!(impact.normal, Vector2::new(1.0, 0.0));
+
+    // Drawing a rotated square
+    let _software_textures = crank::SoftwareTextureScope::new();
+    let mut rasterizer = crank::Rasterizer::new(100, 100);
+    rasterizer.set_clear_color([0.0, 0.0, 0.0, 1.0]);
+    rasterizer.clear();
+
+    let mut batch = crank::RenderBatch::new();
+    batch.set_view(Rectangle::centered(Vector2::zero(), Vector2::new(4.0, 4.0)));
+    batch.set_color([1.0, 1.0, 1.0, 1.0]);
+    batch.fill_oriented_rectangle(&diamond);
+
+    rasterizer.submit_batch(&batch);
+    let image = rasterizer.get_image();
+
+    assert_eq!(image.get_pixel(50, 20), [255, 255, 255, 255]);
+    assert_eq!(image.get_pixel(50, 50), [255, 255, 255, 255]);
+    assert_eq!(image.get_pixel(28, 28), [0, 0, 0, 255]);
+}
+
+
+pub fn circle_physics() {
+    let mut ball = Ball {
+        collider: Circle::new(Vector2::new(0.0, 2.0), 0.5),
+        velocity: Vector2::new(0.0, -4.0),
+    };
+
+    let floor = Rectangle { min: Vector2::new(-4.0, -1.0), max: Vector2::new(4.0, 0.0) };
+
+    // The ball lands on the floor and stays above it
+    for _ in 0..10 {
+        ball.tick(0.1, &[Obstacle::new(&floor)]);
+    }
+
+    assert!(ball.collider.center.y >= 0.5);
+    assert!(ball.collider.center.y < 0.6);
+}
+
+
+struct Ball {
+    collider: Circle,
+    velocity: Vector2,
+}
+
+
+impl PhysicsObject for Ball {
+    type CollisionBody = Circle;
+
+    fn get_position(&self) -> Vector2 { self.collider.center }
+    fn set_position(&mut self, position: Vector2) { self.collider.center = position; }
+
+    fn get_velocity(&self) -> Vector2 { self.velocity }
+    fn set_velocity(&mut self, velocity: Vector2) { self.velocity = velocity; }
+
+    fn get_drag(&self) -> Vector2 { Vector2::zero() }
+    fn set_drag(&mut self, _drag: Vector2) {}
+
+    fn get_collider(&self) -> &Circle { &self.collider }
+}
@@ -23,6 +23,141 @@ mod tests {
     fn platformer() {
         dev::platformer::main()
     }
+
+    #[test]
+    fn headless() {
+        dev::headless::run()
+    }
+
+    #[test]
+    fn render_alpha() {
+        dev::headless::render_alpha()
+    }
+
+    #[test]
+    fn rasterize() {
+        dev::headless::rasterize()
+    }
+
+    #[test]
+    fn renderer_trait() {
+        dev::headless::renderer_trait()
+    }
+
+    #[test]
+    fn transform_stack() {
+        dev::headless::transform_stack()
+    }
+
+    #[test]
+    fn frame_stats() {
+        dev::headless::frame_stats()
+    }
+
+    #[test]
+    fn circle() {
+        dev::shapes::circle()
+    }
+
+    #[test]
+    fn polygon() {
+        dev::shapes::polygon()
+    }
+
+    #[test]
+    fn line_and_triangle() {
+        dev::shapes::line_and_triangle()
+    }
+
+    #[test]
+    fn spatial_grid() {
+        dev::broadphase::spatial_grid()
+    }
+
+    #[test]
+    fn circle_physics() {
+        dev::shapes::circle_physics()
+    }
+
+    #[test]
+    fn ray_hits() {
+        dev::shapes::ray_hits()
+    }
+
+    #[test]
+    fn point_queries() {
+        dev::shapes::point_queries()
+    }
+
+    #[test]
+    fn oriented_rectangle() {
+        dev::shapes::oriented_rectangle()
+    }
+
+    #[test]
+    fn transform() {
+        dev::linear::transform()
+    }
+
+    #[test]
+    fn camera() {
+        dev::camera::camera()
+    }
+
+    #[test]
+    fn window_conversions() {
+        dev::camera::window_conversions()
+    }
+
+    #[test]
+    fn physics_world() {
+        dev::physics::world()
+    }
+
+    #[test]
+    fn physics_materials() {
+        dev::physics::materials()
+    }
+
+    #[test]
+    fn physics_mass() {
+        dev::physics::mass()
+    }
+
+    #[test]
+    fn physics_gravity() {
+        dev::physics::gravity()
+    }
+
+    #[test]
+    fn physics_contacts() {
+        dev::physics::contacts()
+    }
+
+    #[test]
+    fn physics_filters() {
+        dev::physics::filters()
+    }
+
+    #[test]
+    fn physics_triggers() {
+        dev::physics::triggers()
+    }
+
+    #[test]
+    fn physics_kinematics() {
+        dev::physics::kinematics()
+    }
+
+    #[test]
+    fn physics_fast_bodies() {
+        dev::physics::fast_bodies()
+    }
+
+    #[test]
+    fn physics_shape_cast() {
+        dev::physics::shape_cast()
+    }
 }
 
 